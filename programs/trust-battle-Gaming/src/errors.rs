@@ -1,5 +1,6 @@
 use anchor_lang::error_code;
 
+// Error codes are numbered by position, add new variants at the end
 #[error_code]
 pub enum MyError {
     #[msg("Signer must be a registered game client")]
@@ -28,4 +29,121 @@ pub enum MyError {
 
     #[msg("Game is in progress")]
     RpsBasicGameInProgress,
+
+    #[msg("Game timeout was not reached yet")]
+    RpsBasicTimeoutNotReached,
+
+    #[msg("Player2 did not pick a choice for the rematch yet")]
+    RpsBasicRematchPending,
+
+    #[msg("Next choice hash is required to start the next round")]
+    RpsBasicRematchChoiceHashMissing,
+
+    #[msg("Game is not waiting for a rematch")]
+    RpsBasicNoRematch,
+
+    #[msg("Player proof verification failed")]
+    PlayerProofVerify,
+
+    #[msg("Player requires a login proof")]
+    PlayerLoginProofRequired,
+
+    #[msg("Client and platform fee together must be below 10000 BPS")]
+    InvalidFeeBps,
+
+    #[msg("Signer must be the admin")]
+    SignerMustBeAdmin,
+
+    #[msg("Signer must be the pending admin")]
+    SignerMustBePendingAdmin,
+
+    #[msg("Platform is paused")]
+    Paused,
+
+    #[msg("Platform is not paused")]
+    NotPaused,
+
+    #[msg("Game client is suspended")]
+    GameClientSuspended,
+
+    #[msg("Join client share must be at most 10000 BPS")]
+    InvalidShareBps,
+
+    #[msg("Invalid RPS choice")]
+    RpsBasicInvalidChoice,
+
+    #[msg("Result receipt can only be created when the game is decided")]
+    RpsBasicResultNotFinal,
+
+    #[msg("Player2 was not invited to this game")]
+    RpsBasicNotInvited,
+
+    #[msg("Series must be an odd number of rounds, up to 9")]
+    RpsBasicInvalidBestOf,
+
+    #[msg("Mint is not enabled for new games")]
+    MintDisabled,
+
+    #[msg("Not enough lamports to transfer")]
+    InsufficientLamports,
+
+    #[msg("Game is not played with SOL")]
+    RpsBasicNotSolGame,
+
+    #[msg("Minimum stake must be at most the maximum stake")]
+    InvalidStakeLimits,
+
+    #[msg("Amount is above the maximum stake")]
+    RpsBasicAmountTooHigh,
+
+    #[msg("Player is self-excluded")]
+    PlayerSelfExcluded,
+
+    #[msg("Amount is above the player stake limit")]
+    PlayerStakeLimit,
+
+    #[msg("Amount is above the player daily wager limit")]
+    PlayerDailyWagerLimit,
+
+    #[msg("Vkey hash must be 0x followed by 64 hex characters")]
+    InvalidVkeyHash,
+
+    #[msg("Verifier config is not enabled for new games")]
+    VerifierDisabled,
+
+    #[msg("Verifier config is for another circuit")]
+    VerifierCircuitTypeMismatch,
+
+    #[msg("Revealed choice doesn't match the choice hash")]
+    RpsBasicHashMismatch,
+
+    #[msg("Salt is required to reveal a hash game")]
+    RpsBasicSaltRequired,
+
+    #[msg("Verifier config is required for ZK reveal games")]
+    RpsBasicVerifierRequired,
+
+    #[msg("Game id doesn't match the next game id of the client")]
+    RpsBasicGameIdMismatch,
+
+    #[msg("Game client didn't create or join this game")]
+    RpsBasicClientMismatch,
+
+    #[msg("Game timeout has passed")]
+    RpsBasicTimeoutReached,
+
+    #[msg("Groth16 verifying key is empty or too long")]
+    InvalidGroth16Vk,
+
+    #[msg("Player login verifier is required to verify the login proof")]
+    PlayerLoginVerifierRequired,
+
+    #[msg("Game client still has open games")]
+    GameClientHasOpenGames,
+
+    #[msg("SOL stake is too low for its fees to cover rent")]
+    SolStakeBelowRent,
+
+    #[msg("Verifier config is not the one the game was created with")]
+    RpsBasicVerifierMismatch,
 }
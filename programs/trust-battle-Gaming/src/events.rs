@@ -0,0 +1,241 @@
+use anchor_lang::prelude::*;
+
+use crate::{CircuitType, GameResult, RevealMode, RpsBasicOpponent, RpsChoice};
+
+#[event]
+pub struct ManagerUpdated {
+    pub old_client_fee: u16,
+    pub new_client_fee: u16,
+    pub old_platform_fee: u16,
+    pub new_platform_fee: u16,
+    pub old_join_client_share: u16,
+    pub new_join_client_share: u16,
+    pub old_platform_key: Pubkey,
+    pub new_platform_key: Pubkey,
+}
+
+#[event]
+pub struct AdminProposed {
+    pub admin: Pubkey,
+    pub pending_admin: Pubkey,
+}
+
+#[event]
+pub struct AdminTransferred {
+    pub old_admin: Pubkey,
+    pub new_admin: Pubkey,
+}
+
+#[event]
+pub struct MintRegistered {
+    pub mint: Pubkey,
+    pub min_amount: u64,
+    pub max_amount: u64,
+}
+
+#[event]
+pub struct MintUpdated {
+    pub mint: Pubkey,
+    pub old_min_amount: u64,
+    pub new_min_amount: u64,
+    pub old_max_amount: u64,
+    pub new_max_amount: u64,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct ClientStakeLimitsUpdated {
+    pub game_client: Pubkey,
+    pub mint: Pubkey,
+    pub min_amount: u64,
+    pub max_amount: u64,
+}
+
+#[event]
+pub struct VerifierRegistered {
+    pub verifier_config: Pubkey,
+    pub circuit_type: CircuitType,
+    pub version: u16,
+    pub vkey_hash: String,
+    // sha256 of the Groth16 verifying key bytes
+    pub groth16_vk_hash: [u8; 32],
+}
+
+#[event]
+pub struct VerifierUpdated {
+    pub verifier_config: Pubkey,
+    pub circuit_type: CircuitType,
+    pub version: u16,
+    pub enabled: bool,
+}
+
+#[event]
+pub struct PausedUpdated {
+    pub paused: bool,
+}
+
+#[event]
+pub struct RpsBasicGameRefunded {
+    pub game_client: Pubkey,
+    pub id: u64,
+    pub player1: Pubkey,
+    // Not set for games refunded before anyone joined
+    pub player2: Option<Pubkey>,
+    pub player1_amount: u64,
+    pub player2_amount: u64,
+}
+
+#[event]
+pub struct GameClientSuspended {
+    pub game_client: Pubkey,
+    pub suspended: bool,
+}
+
+#[event]
+pub struct GameClientSignerMigrated {
+    pub game_client: Pubkey,
+    pub old_signer: Pubkey,
+    pub new_signer: Pubkey,
+}
+
+#[event]
+pub struct GameClientFeeUpdated {
+    pub game_client: Pubkey,
+    pub old_client_fee: Option<u16>,
+    pub new_client_fee: Option<u16>,
+}
+
+#[event]
+pub struct GameClientClosed {
+    pub game_client: Pubkey,
+}
+
+#[event]
+pub struct GameClientRegistered {
+    pub game_client: Pubkey,
+    pub name: String,
+    pub signer: Pubkey,
+}
+
+#[event]
+pub struct PlayerRegistered {
+    pub player: Pubkey,
+    pub username: String,
+    pub game_client: Pubkey,
+}
+
+#[event]
+pub struct PlayerLoginProofUpdated {
+    pub player: Pubkey,
+    pub require_login_proof: bool,
+}
+
+#[event]
+pub struct PlayerLimitsUpdated {
+    pub player: Pubkey,
+    pub mint: Pubkey,
+    pub max_stake: Option<u64>,
+    pub max_daily_wager: Option<u64>,
+    // Set when a raised limit waits for the cooldown
+    pub pending_effective_at: Option<i64>,
+}
+
+#[event]
+pub struct PlayerExclusionUpdated {
+    pub player: Pubkey,
+    pub excluded_until: i64,
+    // Set when a shorter exclusion waits for the cooldown
+    pub pending_effective_at: Option<i64>,
+}
+
+#[event]
+pub struct RpsBasicPlayerRegistered {
+    pub player: Pubkey,
+    pub game_client: Pubkey,
+}
+
+#[event]
+pub struct RpsBasicGameCreated {
+    pub game_client: Pubkey,
+    pub id: u64,
+    pub player1: Pubkey,
+    pub mint: Pubkey,
+    pub amount: u64,
+    pub client_fee: u16,
+    pub platform_fee: u16,
+    pub max_rematches: u8,
+    pub best_of: u8,
+    pub opponent: Option<RpsBasicOpponent>,
+    pub reveal_mode: RevealMode,
+}
+
+#[event]
+pub struct RpsBasicGameJoined {
+    pub game_client: Pubkey,
+    pub id: u64,
+    pub player2: Pubkey,
+    pub join_client: Pubkey,
+    pub player2_choice: RpsChoice,
+}
+
+#[event]
+pub struct RpsBasicRematchStarted {
+    pub game_client: Pubkey,
+    pub id: u64,
+    pub round: u8,
+    pub player1_choice: RpsChoice,
+    pub player2_choice: RpsChoice,
+    // Result of the previous round
+    pub result: GameResult,
+    pub player1_wins: u8,
+    pub player2_wins: u8,
+}
+
+#[event]
+pub struct RpsBasicRematchPicked {
+    pub game_client: Pubkey,
+    pub id: u64,
+    pub round: u8,
+    pub player2_choice: RpsChoice,
+}
+
+#[event]
+pub struct RpsBasicGameCompleted {
+    pub game_client: Pubkey,
+    pub id: u64,
+    pub player1: Pubkey,
+    pub player2: Pubkey,
+    pub player1_choice: RpsChoice,
+    pub player2_choice: RpsChoice,
+    pub player1_wins: u8,
+    pub player2_wins: u8,
+    pub result: GameResult,
+    pub player1_amount: u64,
+    pub player2_amount: u64,
+    pub origin_client_amount: u64,
+    pub join_client_amount: u64,
+    pub platform_amount: u64,
+}
+
+#[event]
+pub struct RpsBasicTimeoutClaimed {
+    pub game_client: Pubkey,
+    pub id: u64,
+    pub player1: Pubkey,
+    pub player2: Pubkey,
+    pub result: GameResult,
+    pub player1_amount: u64,
+    pub player2_amount: u64,
+    pub origin_client_amount: u64,
+    pub join_client_amount: u64,
+    pub platform_amount: u64,
+}
+
+#[event]
+pub struct RpsBasicGameCancelled {
+    pub game_client: Pubkey,
+    pub id: u64,
+    pub player1: Pubkey,
+    pub player1_amount: u64,
+    pub client_amount: u64,
+}
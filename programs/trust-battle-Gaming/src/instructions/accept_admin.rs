@@ -0,0 +1,32 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::MyError, events::AdminTransferred, Manager};
+
+#[derive(Accounts)]
+pub struct AcceptAdmin<'info> {
+    pending_admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        constraint = manager.pending_admin == Some(pending_admin.key()) @ MyError::SignerMustBePendingAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+}
+
+impl AcceptAdmin<'_> {
+    pub fn accept_admin(&mut self) -> Result<()> {
+        let manager = &mut self.manager;
+
+        emit!(AdminTransferred {
+            old_admin: manager.admin,
+            new_admin: self.pending_admin.key(),
+        });
+
+        manager.admin = self.pending_admin.key();
+        manager.pending_admin = None;
+        Ok(())
+    }
+}
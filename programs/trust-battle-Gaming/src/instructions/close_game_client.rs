@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::MyError, events::GameClientClosed, GameClient, Manager};
+
+#[derive(Accounts)]
+pub struct CloseGameClient<'info> {
+    #[account(mut)]
+    admin: Signer<'info>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(
+        mut,
+        close = admin,
+        seeds=[
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+        constraint = game_client.open_games == 0 @ MyError::GameClientHasOpenGames,
+    )]
+    pub game_client: Account<'info, GameClient>,
+}
+
+impl CloseGameClient<'_> {
+    pub fn close_game_client(&mut self) -> Result<()> {
+        emit!(GameClientClosed {
+            game_client: self.game_client.key(),
+        });
+        Ok(())
+    }
+}
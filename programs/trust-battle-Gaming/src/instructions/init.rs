@@ -1,10 +1,13 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{prelude::*, solana_program::hash::hash};
 use anchor_spl::{
     associated_token::AssociatedToken,
     token_interface::{Mint, TokenAccount, TokenInterface},
 };
 
-use crate::{Manager, ADMIN_PUBKEY};
+use crate::{
+    events::{MintRegistered, VerifierRegistered},
+    CircuitType, Manager, MintConfig, VerifierConfig, ADMIN_PUBKEY, VK_RPS_BASIC_COMPLETE,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitData {
@@ -12,8 +15,13 @@ pub struct InitData {
     pub client_fee_bps: u16,
     // BPS of platform fee
     pub platform_fee_bps: u16,
+    // BPS of the client fee that goes to the joining client
+    pub join_client_share_bps: u16,
     // Pubkey of platform (where to send fee)
     pub platform_key: Pubkey,
+    // Stake limits of the initial mint
+    pub min_amount: u64,
+    pub max_amount: u64,
 }
 
 #[derive(Accounts)]
@@ -31,28 +39,98 @@ pub struct Init<'info> {
         bump
     )]
     pub manager: Account<'info, Manager>,
-    pub usdc_mint: InterfaceAccount<'info, Mint>,
+    // Initial mint, more can be added with `register_mint`
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        space= 8 + MintConfig::INIT_SPACE,
+        payer=admin,
+        seeds=[
+            "mint_config".as_bytes(),
+            mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
     #[account(
         init,
         payer = admin,
-        associated_token::mint = usdc_mint,
+        associated_token::mint = mint,
         associated_token::authority = manager,
+        associated_token::token_program = token_program,
     )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
+    // First version of the RPS basic verifier, using the compiled in keys
+    #[account(
+        init,
+        space= 8 + VerifierConfig::INIT_SPACE,
+        payer=admin,
+        seeds=[
+            "verifier_config".as_bytes(),
+            &[CircuitType::RpsBasic as u8],
+            &0u16.to_le_bytes()
+        ],
+        bump
+    )]
+    pub rps_basic_verifier: Account<'info, VerifierConfig>,
     pub associated_token_program: Program<'info, AssociatedToken>,
     pub token_program: Interface<'info, TokenInterface>,
     system_program: Program<'info, System>,
 }
 
 impl Init<'_> {
-    pub fn init(&mut self, init_data: InitData, bump: u8) -> Result<()> {
+    pub fn init(
+        &mut self,
+        init_data: InitData,
+        bump: u8,
+        mint_config_bump: u8,
+        rps_basic_verifier_bump: u8,
+    ) -> Result<()> {
+        Manager::validate_fees(init_data.client_fee_bps, init_data.platform_fee_bps)?;
+        Manager::validate_join_client_share(init_data.join_client_share_bps)?;
+        MintConfig::validate_limits(init_data.min_amount, init_data.max_amount)?;
+
         self.manager.set_inner(Manager {
+            admin: self.admin.key(),
+            pending_admin: None,
             client_fee: init_data.client_fee_bps,
             platform_fee: init_data.platform_fee_bps,
+            join_client_share: init_data.join_client_share_bps,
             platform_key: init_data.platform_key,
-            usdc_mint: self.usdc_mint.key(),
+            paused: false,
             bump,
         });
+
+        self.mint_config.set_inner(MintConfig {
+            mint: self.mint.key(),
+            min_amount: init_data.min_amount,
+            max_amount: init_data.max_amount,
+            enabled: true,
+            bump: mint_config_bump,
+        });
+
+        emit!(MintRegistered {
+            mint: self.mint.key(),
+            min_amount: init_data.min_amount,
+            max_amount: init_data.max_amount,
+        });
+
+        self.rps_basic_verifier.set_inner(VerifierConfig {
+            circuit_type: CircuitType::RpsBasic,
+            version: 0,
+            vkey_hash: VK_RPS_BASIC_COMPLETE.to_string(),
+            groth16_vk: sp1_solana::GROTH16_VK_4_0_0_RC3_BYTES.to_vec(),
+            enabled: true,
+            bump: rps_basic_verifier_bump,
+        });
+
+        emit!(VerifierRegistered {
+            verifier_config: self.rps_basic_verifier.key(),
+            circuit_type: CircuitType::RpsBasic,
+            version: 0,
+            vkey_hash: VK_RPS_BASIC_COMPLETE.to_string(),
+            groth16_vk_hash: hash(sp1_solana::GROTH16_VK_4_0_0_RC3_BYTES).to_bytes(),
+        });
         Ok(())
     }
 }
@@ -0,0 +1,41 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::MyError, events::GameClientSignerMigrated, GameClient, Manager};
+
+#[derive(Accounts)]
+pub struct MigrateGameClientSigner<'info> {
+    admin: Signer<'info>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(
+        mut,
+        seeds=[
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+    )]
+    pub game_client: Account<'info, GameClient>,
+}
+
+impl MigrateGameClientSigner<'_> {
+    pub fn migrate_game_client_signer(&mut self, new_signer: Pubkey) -> Result<()> {
+        let game_client = &mut self.game_client;
+
+        emit!(GameClientSignerMigrated {
+            game_client: game_client.key(),
+            old_signer: game_client.signer,
+            new_signer,
+        });
+
+        // PDA stays the same (derived from `seed`), so in-flight games are not affected
+        game_client.signer = new_signer;
+        Ok(())
+    }
+}
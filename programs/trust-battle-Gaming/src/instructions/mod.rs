@@ -1,11 +1,68 @@
 pub mod init;
 pub use init::*;
 
+pub mod propose_admin;
+pub use propose_admin::*;
+
+pub mod accept_admin;
+pub use accept_admin::*;
+
+pub mod update_manager;
+pub use update_manager::*;
+
+pub mod set_paused;
+pub use set_paused::*;
+
+pub mod register_mint;
+pub use register_mint::*;
+
+pub mod update_mint;
+pub use update_mint::*;
+
+pub mod register_sol;
+pub use register_sol::*;
+
+pub mod register_verifier;
+pub use register_verifier::*;
+
+pub mod set_verifier_enabled;
+pub use set_verifier_enabled::*;
+
 pub mod register_game_client;
 pub use register_game_client::*;
 
+pub mod set_game_client_suspended;
+pub use set_game_client_suspended::*;
+
+pub mod migrate_game_client_signer;
+pub use migrate_game_client_signer::*;
+
+pub mod set_game_client_fee;
+pub use set_game_client_fee::*;
+
+pub mod set_client_stake_limits;
+pub use set_client_stake_limits::*;
+
+pub mod close_game_client;
+pub use close_game_client::*;
+
 pub mod register_player;
 pub use register_player::*;
 
+pub mod withdraw_player;
+pub use withdraw_player::*;
+
+pub mod set_player_login_proof;
+pub use set_player_login_proof::*;
+
+pub mod set_player_limits;
+pub use set_player_limits::*;
+
+pub mod set_player_exclusion;
+pub use set_player_exclusion::*;
+
+pub mod withdraw_player_sol;
+pub use withdraw_player_sol::*;
+
 pub mod rps_basic;
 pub use rps_basic::*;
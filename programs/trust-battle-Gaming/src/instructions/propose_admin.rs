@@ -0,0 +1,30 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::MyError, events::AdminProposed, Manager};
+
+#[derive(Accounts)]
+pub struct ProposeAdmin<'info> {
+    admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+}
+
+impl ProposeAdmin<'_> {
+    pub fn propose_admin(&mut self, new_admin: Pubkey) -> Result<()> {
+        // New admin must accept before the role is transferred
+        self.manager.pending_admin = Some(new_admin);
+
+        emit!(AdminProposed {
+            admin: self.admin.key(),
+            pending_admin: new_admin,
+        });
+        Ok(())
+    }
+}
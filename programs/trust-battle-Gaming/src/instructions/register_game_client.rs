@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{GameClient, ADMIN_PUBKEY};
+use crate::{errors::MyError, GameClient, Manager};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct GameClientData {
@@ -11,8 +11,16 @@ pub struct GameClientData {
 #[derive(Accounts)]
 #[instruction(game_client_data: GameClientData)]
 pub struct RegisterGameClient<'info> {
-    #[account(mut, address = ADMIN_PUBKEY)]
+    #[account(mut)]
     pub admin: Signer<'info>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
     #[account(
         init,
         space= 8 + GameClient::INIT_SPACE,
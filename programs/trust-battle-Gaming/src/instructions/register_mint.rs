@@ -0,0 +1,66 @@
+use anchor_lang::prelude::*;
+use anchor_spl::{
+    associated_token::AssociatedToken,
+    token_interface::{Mint, TokenAccount, TokenInterface},
+};
+
+use crate::{errors::MyError, events::MintRegistered, Manager, MintConfig};
+
+#[derive(Accounts)]
+pub struct RegisterMint<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        init,
+        space= 8 + MintConfig::INIT_SPACE,
+        payer=admin,
+        seeds=[
+            "mint_config".as_bytes(),
+            mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    // Anyone can create the ATA of the manager, so it might exist already
+    #[account(
+        init_if_needed,
+        payer = admin,
+        associated_token::mint = mint,
+        associated_token::authority = manager,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub associated_token_program: Program<'info, AssociatedToken>,
+    pub token_program: Interface<'info, TokenInterface>,
+    system_program: Program<'info, System>,
+}
+
+impl RegisterMint<'_> {
+    pub fn register_mint(&mut self, min_amount: u64, max_amount: u64, bump: u8) -> Result<()> {
+        MintConfig::validate_limits(min_amount, max_amount)?;
+
+        self.mint_config.set_inner(MintConfig {
+            mint: self.mint.key(),
+            min_amount,
+            max_amount,
+            enabled: true,
+            bump,
+        });
+
+        emit!(MintRegistered {
+            mint: self.mint.key(),
+            min_amount,
+            max_amount,
+        });
+        Ok(())
+    }
+}
@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{errors::MyError, GameClient, Player};
+use crate::{errors::MyError, GameClient, Manager, Player};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct PlayerData {
@@ -27,11 +27,20 @@ pub struct RegisterPlayer<'info> {
     #[account(
         seeds=[
             "game_client".as_bytes(),
-            &game_client.signer.to_bytes()
+            &game_client.seed.to_bytes()
         ],
         bump = game_client.bump,
         has_one = signer @ MyError::SignerMustBeGameClient,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
     )]
-    game_client: Account<'info, GameClient>,
+    pub game_client: Account<'info, GameClient>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        constraint = !manager.paused @ MyError::Paused,
+    )]
+    pub manager: Account<'info, Manager>,
     system_program: Program<'info, System>,
 }
@@ -0,0 +1,70 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::MyError, events::MintRegistered, Manager, MintConfig, SolVault, SOL_MINT};
+
+#[derive(Accounts)]
+pub struct RegisterSol<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+    // SOL is configured like any other mint, under `SOL_MINT`
+    #[account(
+        init,
+        space= 8 + MintConfig::INIT_SPACE,
+        payer=admin,
+        seeds=[
+            "mint_config".as_bytes(),
+            SOL_MINT.as_ref()
+        ],
+        bump
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    #[account(
+        init,
+        space= 8 + SolVault::INIT_SPACE,
+        payer=admin,
+        seeds=[
+            "sol_vault".as_bytes(),
+        ],
+        bump
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+    system_program: Program<'info, System>,
+}
+
+impl RegisterSol<'_> {
+    pub fn register_sol(
+        &mut self,
+        min_amount: u64,
+        max_amount: u64,
+        mint_config_bump: u8,
+        sol_vault_bump: u8,
+    ) -> Result<()> {
+        MintConfig::validate_limits(min_amount, max_amount)?;
+
+        self.mint_config.set_inner(MintConfig {
+            mint: SOL_MINT,
+            min_amount,
+            max_amount,
+            enabled: true,
+            bump: mint_config_bump,
+        });
+        self.sol_vault.set_inner(SolVault {
+            bump: sol_vault_bump,
+        });
+
+        emit!(MintRegistered {
+            mint: SOL_MINT,
+            min_amount,
+            max_amount,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+
+use anchor_lang::solana_program::hash::hash;
+
+use crate::{errors::MyError, events::VerifierRegistered, CircuitType, Manager, VerifierConfig};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RegisterVerifierData {
+    pub circuit_type: CircuitType,
+    // Registered versions can't be changed, new circuits get a new version
+    pub version: u16,
+    // SP1 program vkey hash, "0x" prefixed
+    pub vkey_hash: String,
+    // Groth16 verifying key bytes shipped by sp1-solana for the circuit SP1 version
+    pub groth16_vk: Vec<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(verifier_data: RegisterVerifierData)]
+pub struct RegisterVerifier<'info> {
+    #[account(mut)]
+    pub admin: Signer<'info>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(
+        init,
+        space= 8 + VerifierConfig::INIT_SPACE,
+        payer=admin,
+        seeds=[
+            "verifier_config".as_bytes(),
+            &[verifier_data.circuit_type as u8],
+            &verifier_data.version.to_le_bytes()
+        ],
+        bump
+    )]
+    pub verifier_config: Account<'info, VerifierConfig>,
+    system_program: Program<'info, System>,
+}
+
+impl RegisterVerifier<'_> {
+    pub fn register_verifier(
+        &mut self,
+        verifier_data: RegisterVerifierData,
+        bump: u8,
+    ) -> Result<()> {
+        VerifierConfig::validate_vkey_hash(&verifier_data.vkey_hash)?;
+        VerifierConfig::validate_groth16_vk(&verifier_data.groth16_vk)?;
+
+        emit!(VerifierRegistered {
+            verifier_config: self.verifier_config.key(),
+            circuit_type: verifier_data.circuit_type,
+            version: verifier_data.version,
+            vkey_hash: verifier_data.vkey_hash.clone(),
+            groth16_vk_hash: hash(&verifier_data.groth16_vk).to_bytes(),
+        });
+
+        self.verifier_config.set_inner(VerifierConfig {
+            circuit_type: verifier_data.circuit_type,
+            version: verifier_data.version,
+            vkey_hash: verifier_data.vkey_hash,
+            groth16_vk: verifier_data.groth16_vk,
+            enabled: true,
+            bump,
+        });
+        Ok(())
+    }
+}
@@ -1,11 +1,11 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token::Token,
-    token_interface::{transfer_checked, Mint, TokenAccount, TransferChecked},
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
 };
 
 use crate::{
-    calculate_fee, errors::MyError, GameClient, Manager, Player, RpsBasicGame, RpsBasicPlayer,
+    calculate_fee, errors::MyError, events::RpsBasicGameCancelled, GameClient, Manager, Player,
+    RpsBasicGame, RpsBasicPlayer,
 };
 
 #[derive(Accounts)]
@@ -21,7 +21,8 @@ pub struct CancelRpsBasic<'info> {
             &rps_basic_game.id.to_le_bytes()
         ],
         bump = rps_basic_game.bump,
-        constraint = rps_basic_game.player2.is_none() @ MyError::RpsBasicGameInProgress
+        constraint = player1.key() == rps_basic_game.player1.key @ MyError::RpsBasicPlayer1Mismatch,
+        constraint = rps_basic_game.player2.is_none() @ MyError::RpsBasicGameInProgress,
     )]
     pub rps_basic_game: Account<'info, RpsBasicGame>,
     #[account(
@@ -34,8 +35,9 @@ pub struct CancelRpsBasic<'info> {
     pub player1: Account<'info, Player>,
     #[account(
         mut,
-        associated_token::mint = manager.usdc_mint,
-        associated_token::authority = player1
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = player1,
+        associated_token::token_program = token_program
     )]
     pub player1_ata: InterfaceAccount<'info, TokenAccount>,
     #[account(
@@ -48,18 +50,21 @@ pub struct CancelRpsBasic<'info> {
     )]
     pub player1_rps_basic: Account<'info, RpsBasicPlayer>,
     #[account(
+        mut,
         seeds=[
             "game_client".as_bytes(),
-            &game_client.signer.key().to_bytes()
+            &game_client.seed.to_bytes()
         ],
         bump = game_client.bump,
         has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
     )]
     pub game_client: Account<'info, GameClient>,
     #[account(
         mut,
-        associated_token::mint = manager.usdc_mint,
-        associated_token::authority = signer
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = signer,
+        associated_token::token_program = token_program
     )]
     pub game_client_ata: InterfaceAccount<'info, TokenAccount>,
     #[account(
@@ -69,15 +74,16 @@ pub struct CancelRpsBasic<'info> {
         bump = manager.bump
     )]
     pub manager: Account<'info, Manager>,
-    #[account(address = manager.usdc_mint)]
-    pub usdc_mint: InterfaceAccount<'info, Mint>,
+    #[account(address = rps_basic_game.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
     #[account(
         mut,
-        associated_token::mint = manager.usdc_mint,
+        associated_token::mint = rps_basic_game.mint,
         associated_token::authority = manager,
+        associated_token::token_program = token_program,
     )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     system_program: Program<'info, System>,
 }
 
@@ -88,35 +94,45 @@ impl CancelRpsBasic<'_> {
         let manager_pda_seeds = &["manager".as_bytes(), &[self.manager.bump]];
         let manager_pda_seeds = &[&manager_pda_seeds[..]];
 
-        // calculate fee
+        // calculate fee with the rates the game was created with
         let (send_amount, client_amount, _) =
-            calculate_fee(game.amount, self.manager.client_fee, 0);
+            calculate_fee(game.player1_deposit, game.client_fee, 0);
 
         // We only take client fee for cancellation
         let cpi_accounts = TransferChecked {
-            mint: self.usdc_mint.to_account_info(),
+            mint: self.mint.to_account_info(),
             from: self.vault.to_account_info(),
             to: self.game_client_ata.to_account_info(),
             authority: self.manager.to_account_info(),
         };
         let cpi_program = self.token_program.to_account_info();
         let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, manager_pda_seeds);
-        transfer_checked(cpi_context, client_amount, self.usdc_mint.decimals)?;
+        transfer_checked(cpi_context, client_amount, self.mint.decimals)?;
 
         // Transfer to player1
         let cpi_accounts = TransferChecked {
-            mint: self.usdc_mint.to_account_info(),
+            mint: self.mint.to_account_info(),
             from: self.vault.to_account_info(),
             to: self.player1_ata.to_account_info(),
             authority: self.manager.to_account_info(),
         };
         let cpi_program = self.token_program.to_account_info();
         let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, manager_pda_seeds);
-        transfer_checked(cpi_context, send_amount, self.usdc_mint.decimals)?;
+        transfer_checked(cpi_context, send_amount, self.mint.decimals)?;
 
         let player1_rps_basic = &mut self.player1_rps_basic;
         player1_rps_basic.add_cancel();
 
+        self.game_client.game_closed();
+
+        emit!(RpsBasicGameCancelled {
+            game_client: self.game_client.key(),
+            id: self.rps_basic_game.id,
+            player1: self.player1.key(),
+            player1_amount: send_amount,
+            client_amount,
+        });
+
         Ok(())
     }
 }
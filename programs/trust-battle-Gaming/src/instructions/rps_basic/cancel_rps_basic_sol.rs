@@ -0,0 +1,95 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    calculate_fee, errors::MyError, events::RpsBasicGameCancelled, transfer_lamports, GameClient,
+    Player, RpsBasicGame, RpsBasicPlayer, SolVault,
+};
+
+#[derive(Accounts)]
+pub struct CancelRpsBasicSol<'info> {
+    // Game client signer receives the client fee
+    #[account(mut)]
+    signer: Signer<'info>,
+    #[account(
+        mut,
+        close=signer,
+        seeds=[
+            "rps_basic_game".as_bytes(),
+            game_client.key().as_ref(),
+            &rps_basic_game.id.to_le_bytes()
+        ],
+        bump = rps_basic_game.bump,
+        constraint = rps_basic_game.is_sol() @ MyError::RpsBasicNotSolGame,
+        constraint = player1.key() == rps_basic_game.player1.key @ MyError::RpsBasicPlayer1Mismatch,
+        constraint = rps_basic_game.player2.is_none() @ MyError::RpsBasicGameInProgress,
+    )]
+    pub rps_basic_game: Account<'info, RpsBasicGame>,
+    #[account(
+        mut,
+        seeds=[
+            "player".as_bytes(),
+            player1.username.as_bytes()
+        ],
+        bump = player1.bump
+    )]
+    pub player1: Account<'info, Player>,
+    #[account(
+        mut,
+        seeds = [
+            "rps_basic_player".as_bytes(),
+            player1.username.as_bytes()
+        ],
+        bump = player1_rps_basic.bump
+    )]
+    pub player1_rps_basic: Account<'info, RpsBasicPlayer>,
+    #[account(
+        mut,
+        seeds=[
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+        has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
+    )]
+    pub game_client: Account<'info, GameClient>,
+    #[account(
+        mut,
+        seeds=[
+            "sol_vault".as_bytes(),
+        ],
+        bump = sol_vault.bump,
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+    system_program: Program<'info, System>,
+}
+
+impl CancelRpsBasicSol<'_> {
+    pub fn cancel_rps_basic_sol(&mut self) -> Result<()> {
+        let game = &self.rps_basic_game;
+
+        // calculate fee with the rates the game was created with
+        let (send_amount, client_amount, _) =
+            calculate_fee(game.player1_deposit, game.client_fee, 0);
+
+        let sol_vault = self.sol_vault.to_account_info();
+
+        // We only take client fee for cancellation
+        transfer_lamports(&sol_vault, &self.signer.to_account_info(), client_amount)?;
+        transfer_lamports(&sol_vault, &self.player1.to_account_info(), send_amount)?;
+
+        self.player1_rps_basic.add_cancel();
+
+        self.game_client.game_closed();
+
+        emit!(RpsBasicGameCancelled {
+            game_client: self.game_client.key(),
+            id: self.rps_basic_game.id,
+            player1: self.player1.key(),
+            player1_amount: send_amount,
+            client_amount,
+        });
+
+        Ok(())
+    }
+}
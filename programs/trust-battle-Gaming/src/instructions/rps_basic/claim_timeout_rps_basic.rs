@@ -0,0 +1,193 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    errors::MyError, events::RpsBasicTimeoutClaimed, GameClient, Manager, Player, RpsBasicGame,
+    RpsBasicPlayer,
+};
+
+#[derive(Accounts)]
+pub struct ClaimTimeoutRpsBasic<'info> {
+    #[account(mut)]
+    signer: Signer<'info>,
+    #[account(
+        mut,
+        close = signer,
+        seeds = [
+            "rps_basic_game".as_bytes(),
+            rps_basic_game.game_client.key().as_ref(),
+            &rps_basic_game.id.to_le_bytes()
+        ],
+        bump = rps_basic_game.bump,
+        constraint = player1.key() == rps_basic_game.player1.key @ MyError::RpsBasicPlayer1Mismatch,
+        constraint = rps_basic_game.player2.is_some() @ MyError::RpsBasicGameNotJoined,
+        constraint = player2.key() == rps_basic_game.player2.as_ref().unwrap().key @ MyError::RpsBasicPlayer2Mismatch,
+    )]
+    pub rps_basic_game: Box<Account<'info, RpsBasicGame>>,
+    #[account(
+        seeds = [
+            "player".as_bytes(),
+            player1.username.as_bytes()
+        ],
+        bump = player1.bump
+    )]
+    pub player1: Account<'info, Player>,
+    #[account(
+        mut,
+        seeds = [
+            "rps_basic_player".as_bytes(),
+            player1.username.as_bytes()
+        ],
+        bump = player1_rps_basic.bump
+    )]
+    pub player1_rps_basic: Box<Account<'info, RpsBasicPlayer>>,
+    #[account(
+        mut,
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = player1,
+        associated_token::token_program = token_program
+    )]
+    pub player1_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [
+            "player".as_bytes(),
+            player2.username.as_bytes()
+        ],
+        bump = player2.bump,
+    )]
+    pub player2: Account<'info, Player>,
+    #[account(
+        mut,
+        seeds = [
+            "rps_basic_player".as_bytes(),
+            player2.username.as_bytes()
+        ],
+        bump = player2_rps_basic.bump
+    )]
+    pub player2_rps_basic: Box<Account<'info, RpsBasicPlayer>>,
+    #[account(
+        mut,
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = player2,
+        associated_token::token_program = token_program
+    )]
+    pub player2_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds = [
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+        has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
+        constraint = rps_basic_game.is_game_client(&game_client.key()) @ MyError::RpsBasicClientMismatch,
+    )]
+    pub game_client: Account<'info, GameClient>,
+    // Game client the game was created on
+    #[account(mut, address = rps_basic_game.game_client)]
+    pub origin_client: Box<Account<'info, GameClient>>,
+    #[account(
+        mut,
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = origin_client.signer,
+        associated_token::token_program = token_program
+    )]
+    pub origin_client_ata: InterfaceAccount<'info, TokenAccount>,
+    // Game client player2 joined from
+    #[account(mut, address = rps_basic_game.player2.as_ref().unwrap().game_client)]
+    pub join_client: Box<Account<'info, GameClient>>,
+    #[account(
+        mut,
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = join_client.signer,
+        associated_token::token_program = token_program
+    )]
+    pub join_client_ata: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(address = rps_basic_game.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = manager,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        mut,
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = manager.platform_key,
+        associated_token::token_program = token_program,
+    )]
+    pub platform_ata: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    system_program: Program<'info, System>,
+}
+
+impl<'info> ClaimTimeoutRpsBasic<'info> {
+    pub fn claim_timeout_rps_basic(&mut self) -> Result<()> {
+        let result = self.rps_basic_game.timeout_result()?;
+        let payout = self.rps_basic_game.payout(result);
+
+        // Transfer platform fee
+        self.transfer_from_vault(self.platform_ata.clone(), payout.platform_amount)?;
+
+        // Transfer client fee, split between the origin and the joining client
+        self.transfer_from_vault(self.origin_client_ata.clone(), payout.origin_client_amount)?;
+        self.transfer_from_vault(self.join_client_ata.clone(), payout.join_client_amount)?;
+
+        // Transfer winning amount to the player that didn't forfeit
+        self.transfer_from_vault(self.player1_ata.clone(), payout.player1_amount)?;
+        self.transfer_from_vault(self.player2_ata.clone(), payout.player2_amount)?;
+
+        RpsBasicPlayer::add_result(
+            &mut self.player1_rps_basic,
+            &mut self.player2_rps_basic,
+            result,
+        );
+
+        GameClient::game_settled(&mut self.origin_client, &mut self.join_client);
+
+        emit!(RpsBasicTimeoutClaimed {
+            game_client: self.rps_basic_game.game_client,
+            id: self.rps_basic_game.id,
+            player1: self.player1.key(),
+            player2: self.player2.key(),
+            result,
+            player1_amount: payout.player1_amount,
+            player2_amount: payout.player2_amount,
+            origin_client_amount: payout.origin_client_amount,
+            join_client_amount: payout.join_client_amount,
+            platform_amount: payout.platform_amount,
+        });
+
+        Ok(())
+    }
+
+    fn transfer_from_vault(
+        &mut self,
+        acc_info: InterfaceAccount<'info, TokenAccount>,
+        amount: u64,
+    ) -> Result<()> {
+        let manager_pda_seeds = &["manager".as_bytes(), &[self.manager.bump]];
+        let manager_pda_seeds = &[&manager_pda_seeds[..]];
+
+        let cpi_accounts = TransferChecked {
+            mint: self.mint.to_account_info(),
+            from: self.vault.to_account_info(),
+            to: acc_info.to_account_info(),
+            authority: self.manager.to_account_info(),
+        };
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, manager_pda_seeds);
+        transfer_checked(cpi_context, amount, self.mint.decimals)
+    }
+}
@@ -0,0 +1,168 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::MyError, events::RpsBasicTimeoutClaimed, transfer_lamports, GameClient, Manager,
+    Player, RpsBasicGame, RpsBasicPayout, RpsBasicPlayer, SolVault,
+};
+
+#[derive(Accounts)]
+pub struct ClaimTimeoutRpsBasicSol<'info> {
+    #[account(mut)]
+    signer: Signer<'info>,
+    #[account(
+        mut,
+        close = signer,
+        seeds = [
+            "rps_basic_game".as_bytes(),
+            rps_basic_game.game_client.key().as_ref(),
+            &rps_basic_game.id.to_le_bytes()
+        ],
+        bump = rps_basic_game.bump,
+        constraint = rps_basic_game.is_sol() @ MyError::RpsBasicNotSolGame,
+        constraint = player1.key() == rps_basic_game.player1.key @ MyError::RpsBasicPlayer1Mismatch,
+        constraint = rps_basic_game.player2.is_some() @ MyError::RpsBasicGameNotJoined,
+        constraint = player2.key() == rps_basic_game.player2.as_ref().unwrap().key @ MyError::RpsBasicPlayer2Mismatch,
+    )]
+    pub rps_basic_game: Box<Account<'info, RpsBasicGame>>,
+    #[account(
+        mut,
+        seeds = [
+            "player".as_bytes(),
+            player1.username.as_bytes()
+        ],
+        bump = player1.bump
+    )]
+    pub player1: Account<'info, Player>,
+    #[account(
+        mut,
+        seeds = [
+            "rps_basic_player".as_bytes(),
+            player1.username.as_bytes()
+        ],
+        bump = player1_rps_basic.bump
+    )]
+    pub player1_rps_basic: Box<Account<'info, RpsBasicPlayer>>,
+    #[account(
+        mut,
+        seeds = [
+            "player".as_bytes(),
+            player2.username.as_bytes()
+        ],
+        bump = player2.bump,
+    )]
+    pub player2: Account<'info, Player>,
+    #[account(
+        mut,
+        seeds = [
+            "rps_basic_player".as_bytes(),
+            player2.username.as_bytes()
+        ],
+        bump = player2_rps_basic.bump
+    )]
+    pub player2_rps_basic: Box<Account<'info, RpsBasicPlayer>>,
+    #[account(
+        seeds = [
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+        has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
+        constraint = rps_basic_game.is_game_client(&game_client.key()) @ MyError::RpsBasicClientMismatch,
+    )]
+    pub game_client: Account<'info, GameClient>,
+    // Game client the game was created on
+    #[account(mut, address = rps_basic_game.game_client)]
+    pub origin_client: Box<Account<'info, GameClient>>,
+    #[account(mut, address = origin_client.signer)]
+    pub origin_client_signer: SystemAccount<'info>,
+    // Game client player2 joined from
+    #[account(mut, address = rps_basic_game.player2.as_ref().unwrap().game_client)]
+    pub join_client: Box<Account<'info, GameClient>>,
+    #[account(mut, address = join_client.signer)]
+    pub join_client_signer: SystemAccount<'info>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(
+        mut,
+        seeds=[
+            "sol_vault".as_bytes(),
+        ],
+        bump = sol_vault.bump,
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+    #[account(mut, address = manager.platform_key)]
+    pub platform: SystemAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+impl ClaimTimeoutRpsBasicSol<'_> {
+    pub fn claim_timeout_rps_basic_sol(&mut self) -> Result<()> {
+        let result = self.rps_basic_game.timeout_result()?;
+        let payout = self.rps_basic_game.payout(result);
+        self.pay_out(&payout)?;
+
+        RpsBasicPlayer::add_result(
+            &mut self.player1_rps_basic,
+            &mut self.player2_rps_basic,
+            result,
+        );
+
+        GameClient::game_settled(&mut self.origin_client, &mut self.join_client);
+
+        emit!(RpsBasicTimeoutClaimed {
+            game_client: self.rps_basic_game.game_client,
+            id: self.rps_basic_game.id,
+            player1: self.player1.key(),
+            player2: self.player2.key(),
+            result,
+            player1_amount: payout.player1_amount,
+            player2_amount: payout.player2_amount,
+            origin_client_amount: payout.origin_client_amount,
+            join_client_amount: payout.join_client_amount,
+            platform_amount: payout.platform_amount,
+        });
+
+        Ok(())
+    }
+
+    fn pay_out(&self, payout: &RpsBasicPayout) -> Result<()> {
+        let sol_vault = self.sol_vault.to_account_info();
+
+        // Transfer platform fee
+        transfer_lamports(
+            &sol_vault,
+            &self.platform.to_account_info(),
+            payout.platform_amount,
+        )?;
+
+        // Transfer client fee, split between the origin and the joining client
+        transfer_lamports(
+            &sol_vault,
+            &self.origin_client_signer.to_account_info(),
+            payout.origin_client_amount,
+        )?;
+        transfer_lamports(
+            &sol_vault,
+            &self.join_client_signer.to_account_info(),
+            payout.join_client_amount,
+        )?;
+
+        // Transfer winning amount to the player accounts
+        transfer_lamports(
+            &sol_vault,
+            &self.player1.to_account_info(),
+            payout.player1_amount,
+        )?;
+        transfer_lamports(
+            &sol_vault,
+            &self.player2.to_account_info(),
+            payout.player2_amount,
+        )
+    }
+}
@@ -1,19 +1,23 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token::Token,
-    token_interface::{transfer_checked, Mint, TokenAccount, TransferChecked},
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
 };
-use sp1_solana::verify_proof;
 
 use crate::{
-    calculate_fee, calculate_result, errors::MyError, GameClient, GameResult, Manager, Player,
-    RpsBasicGame, RpsBasicPlayer, VK_RPS_BASIC_COMPLETE,
+    errors::MyError, events::RpsBasicGameCompleted, GameClient, Manager, Player, RpsBasicGame,
+    RpsBasicPlayer, RpsBasicResult, RpsChoice, VerifierConfig,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct CompleteRpsBasicData {
+    // ZK proof of the reveal, left empty for `RevealMode::Hash`
     pub proof: Vec<u8>,
-    pub player1_choice: u8,
+    // Salt of the choice hash, only for `RevealMode::Hash`
+    pub salt: Option<[u8; 32]>,
+    pub player1_choice: RpsChoice,
+    // Player1 commitment for the next round, required when a draw is rematched
+    // or the series isn't decided yet
+    pub next_choice_hash: Option<[u8; 32]>,
 }
 
 #[derive(Accounts)]
@@ -22,7 +26,6 @@ pub struct CompleteRpsBasic<'info> {
     signer: Signer<'info>,
     #[account(
         mut,
-        close = signer,
         seeds = [
             "rps_basic_game".as_bytes(),
             rps_basic_game.game_client.key().as_ref(),
@@ -32,8 +35,27 @@ pub struct CompleteRpsBasic<'info> {
         constraint = player1.key() == rps_basic_game.player1.key @ MyError::RpsBasicPlayer1Mismatch,
         constraint = rps_basic_game.player2.is_some() @ MyError::RpsBasicGameNotJoined,
         constraint = player2.key() == rps_basic_game.player2.as_ref().unwrap().key @ MyError::RpsBasicPlayer2Mismatch,
+        constraint = rps_basic_game.player2.as_ref().unwrap().choice.is_some() @ MyError::RpsBasicRematchPending,
     )]
     pub rps_basic_game: Box<Account<'info, RpsBasicGame>>,
+    // Verifier config the game was created with, only for `RevealMode::Zk`
+    #[account(
+        constraint = rps_basic_game.verifier_config == Some(verifier_config.key()) @ MyError::RpsBasicVerifierMismatch,
+    )]
+    pub verifier_config: Option<Box<Account<'info, VerifierConfig>>>,
+    // Optional receipt that outlives the game account
+    #[account(
+        init,
+        space = 8 + RpsBasicResult::INIT_SPACE,
+        payer = signer,
+        seeds = [
+            "rps_basic_result".as_bytes(),
+            rps_basic_game.game_client.key().as_ref(),
+            &rps_basic_game.id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub rps_basic_result: Option<Box<Account<'info, RpsBasicResult>>>,
     #[account(
         seeds = [
             "player".as_bytes(),
@@ -53,8 +75,9 @@ pub struct CompleteRpsBasic<'info> {
     pub player1_rps_basic: Box<Account<'info, RpsBasicPlayer>>,
     #[account(
         mut,
-        associated_token::mint = manager.usdc_mint,
-        associated_token::authority = player1
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = player1,
+        associated_token::token_program = token_program
     )]
     pub player1_ata: InterfaceAccount<'info, TokenAccount>,
     #[account(
@@ -76,25 +99,41 @@ pub struct CompleteRpsBasic<'info> {
     pub player2_rps_basic: Box<Account<'info, RpsBasicPlayer>>,
     #[account(
         mut,
-        associated_token::mint = manager.usdc_mint,
-        associated_token::authority = player2
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = player2,
+        associated_token::token_program = token_program
     )]
     pub player2_ata: InterfaceAccount<'info, TokenAccount>,
     #[account(
         seeds = [
             "game_client".as_bytes(),
-            &signer.key().to_bytes()
+            &game_client.seed.to_bytes()
         ],
         bump = game_client.bump,
         has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
     )]
     pub game_client: Account<'info, GameClient>,
+    // Game client the game was created on
+    #[account(mut, address = rps_basic_game.game_client)]
+    pub origin_client: Box<Account<'info, GameClient>>,
+    #[account(
+        mut,
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = origin_client.signer,
+        associated_token::token_program = token_program
+    )]
+    pub origin_client_ata: InterfaceAccount<'info, TokenAccount>,
+    // Game client player2 joined from
+    #[account(mut, address = rps_basic_game.player2.as_ref().unwrap().game_client)]
+    pub join_client: Box<Account<'info, GameClient>>,
     #[account(
         mut,
-        associated_token::mint = manager.usdc_mint,
-        associated_token::authority = signer
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = join_client.signer,
+        associated_token::token_program = token_program
     )]
-    pub game_client_ata: InterfaceAccount<'info, TokenAccount>,
+    pub join_client_ata: InterfaceAccount<'info, TokenAccount>,
     #[account(
         seeds=[
             "manager".as_bytes(),
@@ -102,104 +141,126 @@ pub struct CompleteRpsBasic<'info> {
         bump = manager.bump
     )]
     pub manager: Account<'info, Manager>,
-    #[account(address = manager.usdc_mint)]
-    pub usdc_mint: InterfaceAccount<'info, Mint>,
+    #[account(address = rps_basic_game.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
     #[account(
         mut,
-        associated_token::mint = manager.usdc_mint,
+        associated_token::mint = rps_basic_game.mint,
         associated_token::authority = manager,
+        associated_token::token_program = token_program,
     )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
-        associated_token::mint = manager.usdc_mint,
+        associated_token::mint = rps_basic_game.mint,
         associated_token::authority = manager.platform_key,
+        associated_token::token_program = token_program,
     )]
     pub platform_ata: InterfaceAccount<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     system_program: Program<'info, System>,
 }
 
 impl<'info> CompleteRpsBasic<'info> {
-    pub fn complete_rps_basic(&mut self, complete_game_data: CompleteRpsBasicData) -> Result<()> {
-        let vk = sp1_solana::GROTH16_VK_4_0_0_RC3_BYTES;
-        let game = &self.rps_basic_game;
-
-        // Get public input for verification
-        let public_public: Vec<u8> = zk_games_types::RpsBasicPublic {
-            client_pubkey: self.game_client.key().to_string(),
-            game_id: game.id,
-            choice_hash: game.player1.choice_hash,
-            choice: complete_game_data.player1_choice,
-        }
-        .into();
-
-        // Verify proof
-        verify_proof(
+    pub fn complete_rps_basic(
+        &mut self,
+        complete_game_data: CompleteRpsBasicData,
+        result_bump: Option<u8>,
+    ) -> Result<()> {
+        let player1_choice = complete_game_data.player1_choice;
+        self.rps_basic_game.verify_reveal(
+            self.verifier_config
+                .as_deref()
+                .map(|verifier_config| &**verifier_config),
             &complete_game_data.proof,
-            &public_public,
-            VK_RPS_BASIC_COMPLETE,
-            vk,
-        )
-        .map_err(|x| {
-            msg!("{:?}", x);
-            MyError::RpsBasicProofVerify
-        })?;
+            complete_game_data.salt,
+            player1_choice,
+        )?;
 
-        let player1_choice = complete_game_data.player1_choice;
-        let player2_choice = game
+        let player2_choice = self
+            .rps_basic_game
             .player2
             .clone()
             .expect("Player2 must be set to complete game")
-            .choice;
+            .choice
+            .expect("Player2 choice must be set to complete game");
 
-        let game_result = calculate_result(player1_choice, player2_choice);
+        // Every revealed round counts toward the player choices
+        self.player1_rps_basic.add_round(player1_choice);
+        self.player2_rps_basic.add_round(player2_choice);
 
-        let (winning_amount, client_amount, platform_amount) = calculate_fee(
-            game.amount
-                .checked_mul(2)
-                .expect("Mul game winning amount overflow"),
-            self.manager.client_fee,
-            self.manager.platform_fee,
-        );
+        let Some(game_result) = self.rps_basic_game.play_round(
+            player1_choice,
+            player2_choice,
+            complete_game_data.next_choice_hash,
+            Clock::get()?.unix_timestamp,
+        )?
+        else {
+            // Receipt is only written once the game is decided
+            require!(
+                self.rps_basic_result.is_none(),
+                MyError::RpsBasicResultNotFinal
+            );
+            return Ok(());
+        };
+
+        let payout = self.rps_basic_game.payout(game_result);
 
         // Transfer platform fee
-        self.transfer_from_vault(self.platform_ata.clone(), platform_amount)?;
-
-        // Transfer client fee
-        self.transfer_from_vault(self.game_client_ata.clone(), client_amount)?;
-
-        match game_result {
-            GameResult::Player1 => {
-                // Transfer winning amount to player1
-                self.transfer_from_vault(self.player1_ata.clone(), winning_amount)?;
-
-                self.player1_rps_basic.add_win(player1_choice);
-                self.player2_rps_basic.add_lose(player2_choice);
-            }
-            GameResult::Player2 => {
-                // Transfer winning amount to player2
-                self.transfer_from_vault(self.player2_ata.clone(), winning_amount)?;
-
-                self.player1_rps_basic.add_lose(player1_choice);
-                self.player2_rps_basic.add_win(player2_choice);
-            }
-            GameResult::Draw => {
-                // TODO: Create rematch logic
-                // Split winning
-                let split_amount = winning_amount
-                    .checked_div(2)
-                    .expect("Split winning amount zero");
-
-                self.transfer_from_vault(self.player1_ata.clone(), split_amount)?;
-                self.transfer_from_vault(self.player2_ata.clone(), split_amount)?;
-
-                self.player1_rps_basic.add_draw(player1_choice);
-                self.player2_rps_basic.add_draw(player2_choice);
-            }
+        self.transfer_from_vault(self.platform_ata.clone(), payout.platform_amount)?;
+
+        // Transfer client fee, split between the origin and the joining client
+        self.transfer_from_vault(self.origin_client_ata.clone(), payout.origin_client_amount)?;
+        self.transfer_from_vault(self.join_client_ata.clone(), payout.join_client_amount)?;
+
+        // Transfer winning amount, split on a draw
+        self.transfer_from_vault(self.player1_ata.clone(), payout.player1_amount)?;
+        self.transfer_from_vault(self.player2_ata.clone(), payout.player2_amount)?;
+
+        RpsBasicPlayer::add_result(
+            &mut self.player1_rps_basic,
+            &mut self.player2_rps_basic,
+            game_result,
+        );
+
+        emit!(RpsBasicGameCompleted {
+            game_client: self.rps_basic_game.game_client,
+            id: self.rps_basic_game.id,
+            player1: self.player1.key(),
+            player2: self.player2.key(),
+            player1_choice,
+            player2_choice,
+            player1_wins: self.rps_basic_game.player1_wins,
+            player2_wins: self.rps_basic_game.player2_wins,
+            result: game_result,
+            player1_amount: payout.player1_amount,
+            player2_amount: payout.player2_amount,
+            origin_client_amount: payout.origin_client_amount,
+            join_client_amount: payout.join_client_amount,
+            platform_amount: payout.platform_amount,
+        });
+
+        if let Some(rps_basic_result) = self.rps_basic_result.as_mut() {
+            rps_basic_result.set_inner(RpsBasicResult {
+                game_client: self.rps_basic_game.game_client,
+                id: self.rps_basic_game.id,
+                player1: self.player1.key(),
+                player2: self.player2.key(),
+                amount: self.rps_basic_game.amount,
+                player1_choice,
+                player2_choice,
+                best_of: self.rps_basic_game.best_of,
+                player1_wins: self.rps_basic_game.player1_wins,
+                player2_wins: self.rps_basic_game.player2_wins,
+                result: game_result,
+                timestamp: Clock::get()?.unix_timestamp,
+                bump: result_bump.expect("Result bump must be set with result account"),
+            });
         }
 
-        Ok(())
+        GameClient::game_settled(&mut self.origin_client, &mut self.join_client);
+
+        self.rps_basic_game.close(self.signer.to_account_info())
     }
 
     fn transfer_from_vault(
@@ -211,13 +272,13 @@ impl<'info> CompleteRpsBasic<'info> {
         let manager_pda_seeds = &[&manager_pda_seeds[..]];
 
         let cpi_accounts = TransferChecked {
-            mint: self.usdc_mint.to_account_info(),
+            mint: self.mint.to_account_info(),
             from: self.vault.to_account_info(),
             to: acc_info.to_account_info(),
             authority: self.manager.to_account_info(),
         };
         let cpi_program = self.token_program.to_account_info();
         let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, manager_pda_seeds);
-        transfer_checked(cpi_context, amount, self.usdc_mint.decimals)
+        transfer_checked(cpi_context, amount, self.mint.decimals)
     }
 }
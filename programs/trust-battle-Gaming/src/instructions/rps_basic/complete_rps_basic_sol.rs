@@ -0,0 +1,248 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::MyError, events::RpsBasicGameCompleted, transfer_lamports, CompleteRpsBasicData,
+    GameClient, Manager, Player, RpsBasicGame, RpsBasicPayout, RpsBasicPlayer, RpsBasicResult,
+    SolVault, VerifierConfig,
+};
+
+#[derive(Accounts)]
+pub struct CompleteRpsBasicSol<'info> {
+    #[account(mut)]
+    signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds = [
+            "rps_basic_game".as_bytes(),
+            rps_basic_game.game_client.key().as_ref(),
+            &rps_basic_game.id.to_le_bytes()
+        ],
+        bump = rps_basic_game.bump,
+        constraint = rps_basic_game.is_sol() @ MyError::RpsBasicNotSolGame,
+        constraint = player1.key() == rps_basic_game.player1.key @ MyError::RpsBasicPlayer1Mismatch,
+        constraint = rps_basic_game.player2.is_some() @ MyError::RpsBasicGameNotJoined,
+        constraint = player2.key() == rps_basic_game.player2.as_ref().unwrap().key @ MyError::RpsBasicPlayer2Mismatch,
+        constraint = rps_basic_game.player2.as_ref().unwrap().choice.is_some() @ MyError::RpsBasicRematchPending,
+    )]
+    pub rps_basic_game: Box<Account<'info, RpsBasicGame>>,
+    // Verifier config the game was created with, only for `RevealMode::Zk`
+    #[account(
+        constraint = rps_basic_game.verifier_config == Some(verifier_config.key()) @ MyError::RpsBasicVerifierMismatch,
+    )]
+    pub verifier_config: Option<Box<Account<'info, VerifierConfig>>>,
+    // Optional receipt that outlives the game account
+    #[account(
+        init,
+        space = 8 + RpsBasicResult::INIT_SPACE,
+        payer = signer,
+        seeds = [
+            "rps_basic_result".as_bytes(),
+            rps_basic_game.game_client.key().as_ref(),
+            &rps_basic_game.id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub rps_basic_result: Option<Box<Account<'info, RpsBasicResult>>>,
+    #[account(
+        mut,
+        seeds = [
+            "player".as_bytes(),
+            player1.username.as_bytes()
+        ],
+        bump = player1.bump
+    )]
+    pub player1: Account<'info, Player>,
+    #[account(
+        mut,
+        seeds = [
+            "rps_basic_player".as_bytes(),
+            player1.username.as_bytes()
+        ],
+        bump = player1_rps_basic.bump
+    )]
+    pub player1_rps_basic: Box<Account<'info, RpsBasicPlayer>>,
+    #[account(
+        mut,
+        seeds = [
+            "player".as_bytes(),
+            player2.username.as_bytes()
+        ],
+        bump = player2.bump,
+    )]
+    pub player2: Account<'info, Player>,
+    #[account(
+        mut,
+        seeds = [
+            "rps_basic_player".as_bytes(),
+            player2.username.as_bytes()
+        ],
+        bump = player2_rps_basic.bump
+    )]
+    pub player2_rps_basic: Box<Account<'info, RpsBasicPlayer>>,
+    #[account(
+        seeds = [
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+        has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
+    )]
+    pub game_client: Account<'info, GameClient>,
+    // Game client the game was created on
+    #[account(mut, address = rps_basic_game.game_client)]
+    pub origin_client: Box<Account<'info, GameClient>>,
+    #[account(mut, address = origin_client.signer)]
+    pub origin_client_signer: SystemAccount<'info>,
+    // Game client player2 joined from
+    #[account(mut, address = rps_basic_game.player2.as_ref().unwrap().game_client)]
+    pub join_client: Box<Account<'info, GameClient>>,
+    #[account(mut, address = join_client.signer)]
+    pub join_client_signer: SystemAccount<'info>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(
+        mut,
+        seeds=[
+            "sol_vault".as_bytes(),
+        ],
+        bump = sol_vault.bump,
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+    #[account(mut, address = manager.platform_key)]
+    pub platform: SystemAccount<'info>,
+    system_program: Program<'info, System>,
+}
+
+impl CompleteRpsBasicSol<'_> {
+    pub fn complete_rps_basic_sol(
+        &mut self,
+        complete_game_data: CompleteRpsBasicData,
+        result_bump: Option<u8>,
+    ) -> Result<()> {
+        let player1_choice = complete_game_data.player1_choice;
+        self.rps_basic_game.verify_reveal(
+            self.verifier_config
+                .as_deref()
+                .map(|verifier_config| &**verifier_config),
+            &complete_game_data.proof,
+            complete_game_data.salt,
+            player1_choice,
+        )?;
+
+        let player2_choice = self
+            .rps_basic_game
+            .player2
+            .clone()
+            .expect("Player2 must be set to complete game")
+            .choice
+            .expect("Player2 choice must be set to complete game");
+
+        // Every revealed round counts toward the player choices
+        self.player1_rps_basic.add_round(player1_choice);
+        self.player2_rps_basic.add_round(player2_choice);
+
+        let Some(game_result) = self.rps_basic_game.play_round(
+            player1_choice,
+            player2_choice,
+            complete_game_data.next_choice_hash,
+            Clock::get()?.unix_timestamp,
+        )?
+        else {
+            // Receipt is only written once the game is decided
+            require!(
+                self.rps_basic_result.is_none(),
+                MyError::RpsBasicResultNotFinal
+            );
+            return Ok(());
+        };
+
+        let payout = self.rps_basic_game.payout(game_result);
+        self.pay_out(&payout)?;
+
+        RpsBasicPlayer::add_result(
+            &mut self.player1_rps_basic,
+            &mut self.player2_rps_basic,
+            game_result,
+        );
+
+        emit!(RpsBasicGameCompleted {
+            game_client: self.rps_basic_game.game_client,
+            id: self.rps_basic_game.id,
+            player1: self.player1.key(),
+            player2: self.player2.key(),
+            player1_choice,
+            player2_choice,
+            player1_wins: self.rps_basic_game.player1_wins,
+            player2_wins: self.rps_basic_game.player2_wins,
+            result: game_result,
+            player1_amount: payout.player1_amount,
+            player2_amount: payout.player2_amount,
+            origin_client_amount: payout.origin_client_amount,
+            join_client_amount: payout.join_client_amount,
+            platform_amount: payout.platform_amount,
+        });
+
+        if let Some(rps_basic_result) = self.rps_basic_result.as_mut() {
+            rps_basic_result.set_inner(RpsBasicResult {
+                game_client: self.rps_basic_game.game_client,
+                id: self.rps_basic_game.id,
+                player1: self.player1.key(),
+                player2: self.player2.key(),
+                amount: self.rps_basic_game.amount,
+                player1_choice,
+                player2_choice,
+                best_of: self.rps_basic_game.best_of,
+                player1_wins: self.rps_basic_game.player1_wins,
+                player2_wins: self.rps_basic_game.player2_wins,
+                result: game_result,
+                timestamp: Clock::get()?.unix_timestamp,
+                bump: result_bump.expect("Result bump must be set with result account"),
+            });
+        }
+
+        GameClient::game_settled(&mut self.origin_client, &mut self.join_client);
+
+        self.rps_basic_game.close(self.signer.to_account_info())
+    }
+
+    fn pay_out(&self, payout: &RpsBasicPayout) -> Result<()> {
+        let sol_vault = self.sol_vault.to_account_info();
+
+        // Transfer platform fee
+        transfer_lamports(
+            &sol_vault,
+            &self.platform.to_account_info(),
+            payout.platform_amount,
+        )?;
+
+        // Transfer client fee, split between the origin and the joining client
+        transfer_lamports(
+            &sol_vault,
+            &self.origin_client_signer.to_account_info(),
+            payout.origin_client_amount,
+        )?;
+        transfer_lamports(
+            &sol_vault,
+            &self.join_client_signer.to_account_info(),
+            payout.join_client_amount,
+        )?;
+
+        // Transfer winning amount to the player accounts
+        transfer_lamports(
+            &sol_vault,
+            &self.player1.to_account_info(),
+            payout.player1_amount,
+        )?;
+        transfer_lamports(
+            &sol_vault,
+            &self.player2.to_account_info(),
+            payout.player2_amount,
+        )
+    }
+}
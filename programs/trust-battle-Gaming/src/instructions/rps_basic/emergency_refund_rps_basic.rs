@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{
+    errors::MyError, events::RpsBasicGameRefunded, GameClient, Manager, Player, RpsBasicGame,
+};
+
+#[derive(Accounts)]
+pub struct EmergencyRefundRpsBasic<'info> {
+    #[account(mut)]
+    admin: Signer<'info>,
+    // Game client might be suspended already, so the rent goes to the admin
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            "rps_basic_game".as_bytes(),
+            rps_basic_game.game_client.key().as_ref(),
+            &rps_basic_game.id.to_le_bytes()
+        ],
+        bump = rps_basic_game.bump,
+        constraint = player1.key() == rps_basic_game.player1.key @ MyError::RpsBasicPlayer1Mismatch,
+        constraint = player2.as_ref().map(|player2| player2.key()) == rps_basic_game.player2.as_ref().map(|player2| player2.key) @ MyError::RpsBasicPlayer2Mismatch,
+        constraint = player2_ata.is_some() == rps_basic_game.player2.is_some() @ MyError::RpsBasicPlayer2Mismatch,
+        constraint = join_client.is_some() == rps_basic_game.player2.is_some() @ MyError::RpsBasicClientMismatch,
+    )]
+    pub rps_basic_game: Box<Account<'info, RpsBasicGame>>,
+    #[account(
+        seeds = [
+            "player".as_bytes(),
+            player1.username.as_bytes()
+        ],
+        bump = player1.bump
+    )]
+    pub player1: Account<'info, Player>,
+    #[account(
+        mut,
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = player1,
+        associated_token::token_program = token_program
+    )]
+    pub player1_ata: InterfaceAccount<'info, TokenAccount>,
+    // Player2 accounts and the join client are only passed for joined games
+    #[account(
+        seeds = [
+            "player".as_bytes(),
+            player2.username.as_bytes()
+        ],
+        bump = player2.bump,
+    )]
+    pub player2: Option<Account<'info, Player>>,
+    #[account(
+        mut,
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = rps_basic_game.player2_key(),
+        associated_token::token_program = token_program
+    )]
+    pub player2_ata: Option<InterfaceAccount<'info, TokenAccount>>,
+    // Game clients the game was created on and joined from
+    #[account(mut, address = rps_basic_game.game_client)]
+    pub origin_client: Box<Account<'info, GameClient>>,
+    #[account(mut, address = rps_basic_game.join_client())]
+    pub join_client: Option<Box<Account<'info, GameClient>>>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+        constraint = manager.paused @ MyError::NotPaused,
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(address = rps_basic_game.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        mut,
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = manager,
+        associated_token::token_program = token_program,
+    )]
+    pub vault: InterfaceAccount<'info, TokenAccount>,
+    pub token_program: Interface<'info, TokenInterface>,
+    system_program: Program<'info, System>,
+}
+
+impl<'info> EmergencyRefundRpsBasic<'info> {
+    pub fn emergency_refund_rps_basic(&mut self) -> Result<()> {
+        let player1_amount = self.rps_basic_game.player1_deposit;
+        let player2_amount = self.rps_basic_game.player2_deposit;
+
+        // Players get their full deposit back, no fees are taken
+        self.transfer_from_vault(self.player1_ata.clone(), player1_amount)?;
+        if let Some(player2_ata) = self.player2_ata.clone() {
+            self.transfer_from_vault(player2_ata, player2_amount)?;
+        }
+
+        match self.join_client.as_mut() {
+            Some(join_client) => GameClient::game_settled(&mut self.origin_client, join_client),
+            None => self.origin_client.game_closed(),
+        }
+
+        emit!(RpsBasicGameRefunded {
+            game_client: self.rps_basic_game.game_client,
+            id: self.rps_basic_game.id,
+            player1: self.player1.key(),
+            player2: self.player2.as_ref().map(|player2| player2.key()),
+            player1_amount,
+            player2_amount,
+        });
+
+        Ok(())
+    }
+
+    fn transfer_from_vault(
+        &mut self,
+        acc_info: InterfaceAccount<'info, TokenAccount>,
+        amount: u64,
+    ) -> Result<()> {
+        let manager_pda_seeds = &["manager".as_bytes(), &[self.manager.bump]];
+        let manager_pda_seeds = &[&manager_pda_seeds[..]];
+
+        let cpi_accounts = TransferChecked {
+            mint: self.mint.to_account_info(),
+            from: self.vault.to_account_info(),
+            to: acc_info.to_account_info(),
+            authority: self.manager.to_account_info(),
+        };
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, manager_pda_seeds);
+        transfer_checked(cpi_context, amount, self.mint.decimals)
+    }
+}
@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::MyError, events::RpsBasicGameRefunded, transfer_lamports, GameClient, Manager, Player,
+    RpsBasicGame, SolVault,
+};
+
+#[derive(Accounts)]
+pub struct EmergencyRefundRpsBasicSol<'info> {
+    #[account(mut)]
+    admin: Signer<'info>,
+    // Game client might be suspended already, so the rent goes to the admin
+    #[account(
+        mut,
+        close = admin,
+        seeds = [
+            "rps_basic_game".as_bytes(),
+            rps_basic_game.game_client.key().as_ref(),
+            &rps_basic_game.id.to_le_bytes()
+        ],
+        bump = rps_basic_game.bump,
+        constraint = rps_basic_game.is_sol() @ MyError::RpsBasicNotSolGame,
+        constraint = player1.key() == rps_basic_game.player1.key @ MyError::RpsBasicPlayer1Mismatch,
+        constraint = player2.as_ref().map(|player2| player2.key()) == rps_basic_game.player2.as_ref().map(|player2| player2.key) @ MyError::RpsBasicPlayer2Mismatch,
+        constraint = join_client.is_some() == rps_basic_game.player2.is_some() @ MyError::RpsBasicClientMismatch,
+    )]
+    pub rps_basic_game: Box<Account<'info, RpsBasicGame>>,
+    #[account(
+        mut,
+        seeds = [
+            "player".as_bytes(),
+            player1.username.as_bytes()
+        ],
+        bump = player1.bump
+    )]
+    pub player1: Account<'info, Player>,
+    // Player2 and the join client are only passed for joined games
+    #[account(
+        mut,
+        seeds = [
+            "player".as_bytes(),
+            player2.username.as_bytes()
+        ],
+        bump = player2.bump,
+    )]
+    pub player2: Option<Account<'info, Player>>,
+    // Game clients the game was created on and joined from
+    #[account(mut, address = rps_basic_game.game_client)]
+    pub origin_client: Box<Account<'info, GameClient>>,
+    #[account(mut, address = rps_basic_game.join_client())]
+    pub join_client: Option<Box<Account<'info, GameClient>>>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+        constraint = manager.paused @ MyError::NotPaused,
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(
+        mut,
+        seeds=[
+            "sol_vault".as_bytes(),
+        ],
+        bump = sol_vault.bump,
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+    system_program: Program<'info, System>,
+}
+
+impl EmergencyRefundRpsBasicSol<'_> {
+    pub fn emergency_refund_rps_basic_sol(&mut self) -> Result<()> {
+        let player1_amount = self.rps_basic_game.player1_deposit;
+        let player2_amount = self.rps_basic_game.player2_deposit;
+        let sol_vault = self.sol_vault.to_account_info();
+
+        // Players get their full deposit back, no fees are taken
+        transfer_lamports(&sol_vault, &self.player1.to_account_info(), player1_amount)?;
+        if let Some(player2) = self.player2.as_ref() {
+            transfer_lamports(&sol_vault, &player2.to_account_info(), player2_amount)?;
+        }
+
+        match self.join_client.as_mut() {
+            Some(join_client) => GameClient::game_settled(&mut self.origin_client, join_client),
+            None => self.origin_client.game_closed(),
+        }
+
+        emit!(RpsBasicGameRefunded {
+            game_client: self.rps_basic_game.game_client,
+            id: self.rps_basic_game.id,
+            player1: self.player1.key(),
+            player2: self.player2.as_ref().map(|player2| player2.key()),
+            player1_amount,
+            player2_amount,
+        });
+
+        Ok(())
+    }
+}
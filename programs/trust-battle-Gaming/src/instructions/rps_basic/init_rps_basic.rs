@@ -1,20 +1,34 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token::Token,
-    token_interface::{transfer_checked, Mint, TokenAccount, TransferChecked},
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
 };
 
-use crate::{errors::MyError, GameClient, Manager, Player, Player1Info, RpsBasicGame};
+use crate::{
+    errors::MyError, events::RpsBasicGameCreated, CircuitType, ClientMintConfig, GameClient,
+    Manager, MintConfig, Player, Player1Info, PlayerLimits, RevealMode, RpsBasicGame,
+    RpsBasicOpponent, VerifierConfig,
+};
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct InitRpsBasicData {
-    pub id: u64,
+    // Next game id of the client, player1 choice hash commits to it
+    pub expected_id: u64,
     pub amount: u64,
     pub choice_hash: [u8; 32],
+    // How player1 reveals his choices, `RevealMode::Zk` requires a verifier config
+    pub reveal_mode: RevealMode,
+    // How many times a draw is replayed before splitting the pot (0 = no rematch)
+    pub max_rematches: u8,
+    // Rounds in the series (1, 3, 5...), 1 for a single game
+    pub best_of: u8,
+    // Invite a single opponent, leave empty for a public game
+    pub opponent: Option<RpsBasicOpponent>,
+    // Player1 login proof, required if player1 opted in
+    pub login_proof: Option<Vec<u8>>,
 }
 
 #[derive(Accounts)]
-#[instruction(init_rps_basic_data: InitRpsBasicData)]
+#[instruction(init_game_data: InitRpsBasicData)]
 pub struct InitRpsBasic<'info> {
     #[account(mut)]
     signer: Signer<'info>,
@@ -25,7 +39,7 @@ pub struct InitRpsBasic<'info> {
         seeds=[
             "rps_basic_game".as_bytes(),
             game_client.key().as_ref(),
-            &init_rps_basic_data.id.to_le_bytes()
+            &init_game_data.expected_id.to_le_bytes()
         ],
         bump
     )]
@@ -39,50 +53,124 @@ pub struct InitRpsBasic<'info> {
         bump = player1.bump
     )]
     pub player1: Account<'info, Player>,
+    // Responsible-gaming limits of player1 for the mint
+    #[account(
+        init_if_needed,
+        space= 8 + PlayerLimits::INIT_SPACE,
+        payer=signer,
+        seeds=[
+            "player_limits".as_bytes(),
+            player1.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump
+    )]
+    pub player1_limits: Box<Account<'info, PlayerLimits>>,
     #[account(
         mut,
-        associated_token::mint = manager.usdc_mint,
-        associated_token::authority = player1
+        associated_token::mint = mint,
+        associated_token::authority = player1,
+        associated_token::token_program = token_program
     )]
     pub player1_ata: InterfaceAccount<'info, TokenAccount>,
     #[account(
         mut,
         seeds=[
             "game_client".as_bytes(),
-            &game_client.signer.key().to_bytes()
+            &game_client.seed.to_bytes()
         ],
         bump = game_client.bump,
         has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
+        constraint = game_client.game_count == init_game_data.expected_id @ MyError::RpsBasicGameIdMismatch,
     )]
     pub game_client: Account<'info, GameClient>,
+    // Verifier of player login proofs, only needed if the player requires them
+    #[account(
+        constraint = login_verifier.circuit_type == CircuitType::PlayerLogin @ MyError::VerifierCircuitTypeMismatch,
+        constraint = login_verifier.enabled @ MyError::VerifierDisabled,
+    )]
+    pub login_verifier: Option<Box<Account<'info, VerifierConfig>>>,
     #[account(
         seeds=[
             "manager".as_bytes(),
         ],
-        bump = manager.bump
+        bump = manager.bump,
+        constraint = !manager.paused @ MyError::Paused,
     )]
     pub manager: Account<'info, Manager>,
-    #[account(address = manager.usdc_mint)]
-    pub usdc_mint: InterfaceAccount<'info, Mint>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    #[account(
+        seeds=[
+            "mint_config".as_bytes(),
+            mint.key().as_ref()
+        ],
+        bump = mint_config.bump,
+        constraint = mint_config.enabled @ MyError::MintDisabled,
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    // Stake limits of the game client, always passed so they can't be skipped
+    /// CHECK: client mint config PDA, only read if the client set its limits
+    #[account(
+        seeds=[
+            "client_mint_config".as_bytes(),
+            game_client.key().as_ref(),
+            mint.key().as_ref()
+        ],
+        bump,
+    )]
+    pub client_mint_config: UncheckedAccount<'info>,
+    // Verifier the reveal of this game will be checked with, only for `RevealMode::Zk`
+    #[account(
+        seeds=[
+            "verifier_config".as_bytes(),
+            &[verifier_config.circuit_type as u8],
+            &verifier_config.version.to_le_bytes()
+        ],
+        bump = verifier_config.bump,
+        constraint = verifier_config.circuit_type == CircuitType::RpsBasic @ MyError::VerifierCircuitTypeMismatch,
+        constraint = verifier_config.enabled @ MyError::VerifierDisabled,
+    )]
+    pub verifier_config: Option<Box<Account<'info, VerifierConfig>>>,
     #[account(
         mut,
-        associated_token::mint = manager.usdc_mint,
+        associated_token::mint = mint,
         associated_token::authority = manager,
+        associated_token::token_program = token_program,
     )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     system_program: Program<'info, System>,
 }
 
 impl InitRpsBasic<'_> {
-    pub fn init_rps_basic(&mut self, init_game_data: InitRpsBasicData, bump: u8) -> Result<()> {
-        let decimals = self.usdc_mint.decimals;
+    pub fn init_rps_basic(
+        &mut self,
+        init_game_data: InitRpsBasicData,
+        bump: u8,
+        player1_limits_bump: u8,
+    ) -> Result<()> {
+        let decimals = self.mint.decimals;
 
-        // Check amount is above minimum
-        require!(
-            init_game_data.amount >= 10_u64.pow(decimals as u32),
-            MyError::RpsBasicAmountTooLow
-        );
+        self.player1.verify_login(
+            self.game_client.key(),
+            self.login_verifier
+                .as_deref()
+                .map(|login_verifier| &**login_verifier),
+            init_game_data.login_proof.as_deref(),
+        )?;
+
+        let client_mint_config = ClientMintConfig::load(&self.client_mint_config)?;
+        init_game_data.validate(
+            &self.mint_config,
+            client_mint_config.as_ref(),
+            self.verifier_config.is_some(),
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        self.player1.check_exclusion(now)?;
+        self.player1_limits.bump = player1_limits_bump;
+        self.player1_limits.add_wager(init_game_data.amount, now)?;
 
         let player1_pda_seeds = &[
             "player".as_bytes(),
@@ -91,8 +179,11 @@ impl InitRpsBasic<'_> {
         ];
         let player1_pda_seeds = &[&player1_pda_seeds[..]];
 
+        // Transfer fee extension can take a cut, so measure what the vault got
+        let vault_amount = self.vault.amount;
+
         let cpi_accounts = TransferChecked {
-            mint: self.usdc_mint.to_account_info(),
+            mint: self.mint.to_account_info(),
             from: self.player1_ata.to_account_info(),
             to: self.vault.to_account_info(),
             authority: self.player1.to_account_info(),
@@ -101,19 +192,116 @@ impl InitRpsBasic<'_> {
         let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, player1_pda_seeds);
         transfer_checked(cpi_context, init_game_data.amount, decimals)?;
 
-        self.rps_basic_game.set_inner(RpsBasicGame {
-            id: init_game_data.id,
-            amount: init_game_data.amount,
-            player1: Player1Info {
-                key: self.player1.key(),
-                choice_hash: init_game_data.choice_hash,
-            },
-            player2: None,
-            timeout: None,
-            game_client: self.game_client.key(),
+        self.vault.reload()?;
+        let player1_deposit = self
+            .vault
+            .amount
+            .checked_sub(vault_amount)
+            .expect("Vault balance can't drop on a deposit");
+
+        create_rps_basic_game(
+            &mut self.rps_basic_game,
+            &mut self.game_client,
+            &self.manager,
+            self.player1.key(),
+            self.mint.key(),
+            self.verifier_config
+                .as_ref()
+                .map(|verifier_config| verifier_config.key()),
+            player1_deposit,
+            init_game_data,
             bump,
-        });
+        )
+    }
+}
 
-        Ok(())
+impl InitRpsBasicData {
+    pub fn validate(
+        &self,
+        mint_config: &MintConfig,
+        client_mint_config: Option<&ClientMintConfig>,
+        has_verifier_config: bool,
+    ) -> Result<()> {
+        mint_config.check_amount(self.amount, client_mint_config)?;
+
+        require!(
+            self.reveal_mode == RevealMode::Hash || has_verifier_config,
+            MyError::RpsBasicVerifierRequired
+        );
+
+        RpsBasicGame::validate_series(self.best_of, self.max_rematches)
+    }
+}
+
+/// Create the game once player1 stake is in the vault, shared by the token and SOL flows
+#[allow(clippy::too_many_arguments)]
+pub fn create_rps_basic_game(
+    rps_basic_game: &mut Account<RpsBasicGame>,
+    game_client: &mut Account<GameClient>,
+    manager: &Manager,
+    player1: Pubkey,
+    mint: Pubkey,
+    verifier_config: Option<Pubkey>,
+    player1_deposit: u64,
+    init_game_data: InitRpsBasicData,
+    bump: u8,
+) -> Result<()> {
+    // Fees are snapshotted on the game, the client override must still fit with the platform fee
+    let client_fee = game_client.client_fee(manager);
+    Manager::validate_fees(client_fee, manager.platform_fee)?;
+
+    // Game id is assigned by the client counter
+    let id = game_client.game_count;
+    game_client.game_count += 1;
+    game_client.game_opened();
+
+    rps_basic_game.set_inner(RpsBasicGame {
+        id,
+        amount: init_game_data.amount,
+        player1_deposit,
+        player2_deposit: 0,
+        client_fee,
+        platform_fee: manager.platform_fee,
+        join_client_share: manager.join_client_share,
+        player1: Player1Info {
+            key: player1,
+            choice_hash: init_game_data.choice_hash,
+        },
+        player2: None,
+        opponent: init_game_data.opponent.clone(),
+        timeout: None,
+        rematches_left: init_game_data.max_rematches,
+        round: 0,
+        best_of: init_game_data.best_of,
+        player1_wins: 0,
+        player2_wins: 0,
+        drawn_rounds: 0,
+        game_client: game_client.key(),
+        mint,
+        reveal_mode: init_game_data.reveal_mode,
+        // Hash games don't use the verifier
+        verifier_config: verifier_config.filter(|_| init_game_data.reveal_mode == RevealMode::Zk),
+        bump,
+    });
+
+    // Checked on the fees snapshotted above, later fee changes don't apply to the game
+    if rps_basic_game.is_sol() {
+        rps_basic_game.validate_sol_fees(Rent::get()?.minimum_balance(0))?;
     }
+
+    emit!(RpsBasicGameCreated {
+        game_client: game_client.key(),
+        id,
+        player1,
+        mint,
+        amount: init_game_data.amount,
+        client_fee: rps_basic_game.client_fee,
+        platform_fee: rps_basic_game.platform_fee,
+        max_rematches: init_game_data.max_rematches,
+        best_of: init_game_data.best_of,
+        opponent: init_game_data.opponent,
+        reveal_mode: init_game_data.reveal_mode,
+    });
+
+    Ok(())
 }
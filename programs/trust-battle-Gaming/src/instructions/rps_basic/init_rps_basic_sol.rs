@@ -0,0 +1,165 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    create_rps_basic_game, errors::MyError, transfer_lamports, CircuitType, ClientMintConfig,
+    GameClient, InitRpsBasicData, Manager, MintConfig, Player, PlayerLimits, RpsBasicGame,
+    SolVault, VerifierConfig, SOL_MINT,
+};
+
+#[derive(Accounts)]
+#[instruction(init_game_data: InitRpsBasicData)]
+pub struct InitRpsBasicSol<'info> {
+    #[account(mut)]
+    signer: Signer<'info>,
+    #[account(
+        init,
+        space= 8 + RpsBasicGame::INIT_SPACE,
+        payer=signer,
+        seeds=[
+            "rps_basic_game".as_bytes(),
+            game_client.key().as_ref(),
+            &init_game_data.expected_id.to_le_bytes()
+        ],
+        bump
+    )]
+    pub rps_basic_game: Account<'info, RpsBasicGame>,
+    // Player1 stake is taken from the lamports of his player account
+    #[account(
+        mut,
+        seeds=[
+            "player".as_bytes(),
+            player1.username.as_bytes()
+        ],
+        bump = player1.bump
+    )]
+    pub player1: Account<'info, Player>,
+    // Responsible-gaming limits of player1 for the mint
+    #[account(
+        init_if_needed,
+        space= 8 + PlayerLimits::INIT_SPACE,
+        payer=signer,
+        seeds=[
+            "player_limits".as_bytes(),
+            player1.key().as_ref(),
+            SOL_MINT.as_ref()
+        ],
+        bump
+    )]
+    pub player1_limits: Box<Account<'info, PlayerLimits>>,
+    #[account(
+        mut,
+        seeds=[
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+        has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
+        constraint = game_client.game_count == init_game_data.expected_id @ MyError::RpsBasicGameIdMismatch,
+    )]
+    pub game_client: Account<'info, GameClient>,
+    // Verifier of player login proofs, only needed if the player requires them
+    #[account(
+        constraint = login_verifier.circuit_type == CircuitType::PlayerLogin @ MyError::VerifierCircuitTypeMismatch,
+        constraint = login_verifier.enabled @ MyError::VerifierDisabled,
+    )]
+    pub login_verifier: Option<Box<Account<'info, VerifierConfig>>>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        constraint = !manager.paused @ MyError::Paused,
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(
+        seeds=[
+            "mint_config".as_bytes(),
+            SOL_MINT.as_ref()
+        ],
+        bump = mint_config.bump,
+        constraint = mint_config.enabled @ MyError::MintDisabled,
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+    // Stake limits of the game client, always passed so they can't be skipped
+    /// CHECK: client mint config PDA, only read if the client set its limits
+    #[account(
+        seeds=[
+            "client_mint_config".as_bytes(),
+            game_client.key().as_ref(),
+            SOL_MINT.as_ref()
+        ],
+        bump,
+    )]
+    pub client_mint_config: UncheckedAccount<'info>,
+    // Verifier the reveal of this game will be checked with, only for `RevealMode::Zk`
+    #[account(
+        seeds=[
+            "verifier_config".as_bytes(),
+            &[verifier_config.circuit_type as u8],
+            &verifier_config.version.to_le_bytes()
+        ],
+        bump = verifier_config.bump,
+        constraint = verifier_config.circuit_type == CircuitType::RpsBasic @ MyError::VerifierCircuitTypeMismatch,
+        constraint = verifier_config.enabled @ MyError::VerifierDisabled,
+    )]
+    pub verifier_config: Option<Box<Account<'info, VerifierConfig>>>,
+    #[account(
+        mut,
+        seeds=[
+            "sol_vault".as_bytes(),
+        ],
+        bump = sol_vault.bump,
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+    system_program: Program<'info, System>,
+}
+
+impl InitRpsBasicSol<'_> {
+    pub fn init_rps_basic_sol(
+        &mut self,
+        init_game_data: InitRpsBasicData,
+        bump: u8,
+        player1_limits_bump: u8,
+    ) -> Result<()> {
+        self.player1.verify_login(
+            self.game_client.key(),
+            self.login_verifier
+                .as_deref()
+                .map(|login_verifier| &**login_verifier),
+            init_game_data.login_proof.as_deref(),
+        )?;
+
+        let client_mint_config = ClientMintConfig::load(&self.client_mint_config)?;
+        init_game_data.validate(
+            &self.mint_config,
+            client_mint_config.as_ref(),
+            self.verifier_config.is_some(),
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        self.player1.check_exclusion(now)?;
+        self.player1_limits.bump = player1_limits_bump;
+        self.player1_limits.add_wager(init_game_data.amount, now)?;
+
+        transfer_lamports(
+            &self.player1.to_account_info(),
+            &self.sol_vault.to_account_info(),
+            init_game_data.amount,
+        )?;
+
+        create_rps_basic_game(
+            &mut self.rps_basic_game,
+            &mut self.game_client,
+            &self.manager,
+            self.player1.key(),
+            SOL_MINT,
+            self.verifier_config
+                .as_ref()
+                .map(|verifier_config| verifier_config.key()),
+            init_game_data.amount,
+            init_game_data,
+            bump,
+        )
+    }
+}
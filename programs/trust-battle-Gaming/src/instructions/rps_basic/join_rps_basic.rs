@@ -1,17 +1,19 @@
 use anchor_lang::prelude::*;
-use anchor_spl::{
-    token::Token,
-    token_interface::{transfer_checked, Mint, TokenAccount, TransferChecked},
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
 };
 
 use crate::{
-    errors::MyError, GameClient, Manager, Player, Player2Info, RpsBasicGame,
-    DEFAULT_RPS_BASIC_TIMEOUT,
+    errors::MyError, CircuitType, GameClient, Manager, Player, PlayerLimits, RpsBasicGame,
+    RpsChoice, VerifierConfig,
 };
 
 #[derive(AnchorSerialize, AnchorDeserialize)]
 pub struct JoinRpsBasicData {
+    // Raw choice, validated to reject invalid values with a proper error
     pub player2_choice: u8,
+    // Player2 login proof, required if player2 opted in
+    pub login_proof: Option<Vec<u8>>,
 }
 
 #[derive(Accounts)]
@@ -29,6 +31,7 @@ pub struct JoinRpsBasic<'info> {
         constraint = player1.key() != player2.key() @ MyError::RpsBasicSamePlayer,
         constraint = player1.key() == rps_basic_game.player1.key @ MyError::RpsBasicPlayer1Mismatch,
         constraint = rps_basic_game.player2.is_none() @ MyError::RpsBasicGameJoined,
+        constraint = rps_basic_game.can_join(&player2.key(), &player2.username) @ MyError::RpsBasicNotInvited,
     )]
     pub rps_basic_game: Account<'info, RpsBasicGame>,
     #[account(
@@ -40,6 +43,7 @@ pub struct JoinRpsBasic<'info> {
     )]
     pub player1: Account<'info, Player>,
     #[account(
+        mut,
         seeds=[
             "player".as_bytes(),
             player2.username.as_bytes()
@@ -47,42 +51,86 @@ pub struct JoinRpsBasic<'info> {
         bump = player2.bump,
     )]
     pub player2: Account<'info, Player>,
+    // Responsible-gaming limits of player2 for the mint
+    #[account(
+        init_if_needed,
+        space= 8 + PlayerLimits::INIT_SPACE,
+        payer=signer,
+        seeds=[
+            "player_limits".as_bytes(),
+            player2.key().as_ref(),
+            rps_basic_game.mint.as_ref()
+        ],
+        bump
+    )]
+    pub player2_limits: Box<Account<'info, PlayerLimits>>,
     #[account(
         mut,
-        associated_token::mint = manager.usdc_mint,
-        associated_token::authority = player2
+        associated_token::mint = rps_basic_game.mint,
+        associated_token::authority = player2,
+        associated_token::token_program = token_program
     )]
     pub player2_ata: InterfaceAccount<'info, TokenAccount>,
     #[account(
+        mut,
         seeds=[
             "game_client".as_bytes(),
-            &game_client.signer.key().to_bytes()
+            &game_client.seed.to_bytes()
         ],
         bump = game_client.bump,
         has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
     )]
     game_client: Account<'info, GameClient>,
+    // Verifier of player login proofs, only needed if the player requires them
+    #[account(
+        constraint = login_verifier.circuit_type == CircuitType::PlayerLogin @ MyError::VerifierCircuitTypeMismatch,
+        constraint = login_verifier.enabled @ MyError::VerifierDisabled,
+    )]
+    pub login_verifier: Option<Box<Account<'info, VerifierConfig>>>,
     #[account(
         seeds=[
             "manager".as_bytes(),
         ],
-        bump = manager.bump
+        bump = manager.bump,
+        constraint = !manager.paused @ MyError::Paused,
     )]
     pub manager: Account<'info, Manager>,
-    #[account(address = manager.usdc_mint)]
-    pub usdc_mint: InterfaceAccount<'info, Mint>,
+    #[account(address = rps_basic_game.mint)]
+    pub mint: InterfaceAccount<'info, Mint>,
     #[account(
         mut,
-        associated_token::mint = manager.usdc_mint,
+        associated_token::mint = rps_basic_game.mint,
         associated_token::authority = manager,
+        associated_token::token_program = token_program,
     )]
     pub vault: InterfaceAccount<'info, TokenAccount>,
-    pub token_program: Program<'info, Token>,
+    pub token_program: Interface<'info, TokenInterface>,
     system_program: Program<'info, System>,
 }
 
 impl JoinRpsBasic<'_> {
-    pub fn join_rps_basic(&mut self, join_game_data: JoinRpsBasicData) -> Result<()> {
+    pub fn join_rps_basic(
+        &mut self,
+        join_game_data: JoinRpsBasicData,
+        player2_limits_bump: u8,
+    ) -> Result<()> {
+        let player2_choice = RpsChoice::try_from(join_game_data.player2_choice)?;
+
+        self.player2.verify_login(
+            self.game_client.key(),
+            self.login_verifier
+                .as_deref()
+                .map(|login_verifier| &**login_verifier),
+            join_game_data.login_proof.as_deref(),
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        self.player2.check_exclusion(now)?;
+        self.player2_limits.bump = player2_limits_bump;
+        self.player2_limits
+            .add_wager(self.rps_basic_game.amount, now)?;
+
         let game = &mut self.rps_basic_game;
 
         let player2_pda_seeds = &[
@@ -92,22 +140,33 @@ impl JoinRpsBasic<'_> {
         ];
         let player2_pda_seeds = &[&player2_pda_seeds[..]];
 
+        // Transfer fee extension can take a cut, so measure what the vault got
+        let vault_amount = self.vault.amount;
+
         let cpi_accounts = TransferChecked {
-            mint: self.usdc_mint.to_account_info(),
+            mint: self.mint.to_account_info(),
             from: self.player2_ata.to_account_info(),
             to: self.vault.to_account_info(),
             authority: self.player2.to_account_info(),
         };
         let cpi_program = self.token_program.to_account_info();
         let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, player2_pda_seeds);
-        transfer_checked(cpi_context, game.amount, self.usdc_mint.decimals)?;
+        transfer_checked(cpi_context, game.amount, self.mint.decimals)?;
+
+        self.vault.reload()?;
+        let player2_deposit = self
+            .vault
+            .amount
+            .checked_sub(vault_amount)
+            .expect("Vault balance can't drop on a deposit");
 
-        game.player2 = Some(Player2Info {
-            key: self.player2.key(),
-            choice: join_game_data.player2_choice,
-        });
+        self.game_client.game_opened();
 
-        game.timeout = Some(Clock::get()?.unix_timestamp + DEFAULT_RPS_BASIC_TIMEOUT);
-        Ok(())
+        game.join(
+            self.player2.key(),
+            self.game_client.key(),
+            player2_choice,
+            player2_deposit,
+        )
     }
 }
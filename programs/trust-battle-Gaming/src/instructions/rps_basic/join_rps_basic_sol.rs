@@ -0,0 +1,132 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::MyError, transfer_lamports, CircuitType, GameClient, JoinRpsBasicData, Manager, Player,
+    PlayerLimits, RpsBasicGame, RpsChoice, SolVault, VerifierConfig, SOL_MINT,
+};
+
+#[derive(Accounts)]
+pub struct JoinRpsBasicSol<'info> {
+    #[account(mut)]
+    signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            "rps_basic_game".as_bytes(),
+            rps_basic_game.game_client.key().as_ref(),
+            &rps_basic_game.id.to_le_bytes()
+        ],
+        bump = rps_basic_game.bump,
+        constraint = rps_basic_game.is_sol() @ MyError::RpsBasicNotSolGame,
+        constraint = player1.key() != player2.key() @ MyError::RpsBasicSamePlayer,
+        constraint = player1.key() == rps_basic_game.player1.key @ MyError::RpsBasicPlayer1Mismatch,
+        constraint = rps_basic_game.player2.is_none() @ MyError::RpsBasicGameJoined,
+        constraint = rps_basic_game.can_join(&player2.key(), &player2.username) @ MyError::RpsBasicNotInvited,
+    )]
+    pub rps_basic_game: Account<'info, RpsBasicGame>,
+    #[account(
+        seeds=[
+            "player".as_bytes(),
+            player1.username.as_bytes()
+        ],
+        bump = player1.bump
+    )]
+    pub player1: Account<'info, Player>,
+    // Player2 stake is taken from the lamports of his player account
+    #[account(
+        mut,
+        seeds=[
+            "player".as_bytes(),
+            player2.username.as_bytes()
+        ],
+        bump = player2.bump,
+    )]
+    pub player2: Account<'info, Player>,
+    // Responsible-gaming limits of player2 for the mint
+    #[account(
+        init_if_needed,
+        space= 8 + PlayerLimits::INIT_SPACE,
+        payer=signer,
+        seeds=[
+            "player_limits".as_bytes(),
+            player2.key().as_ref(),
+            SOL_MINT.as_ref()
+        ],
+        bump
+    )]
+    pub player2_limits: Box<Account<'info, PlayerLimits>>,
+    #[account(
+        mut,
+        seeds=[
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+        has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
+    )]
+    game_client: Account<'info, GameClient>,
+    // Verifier of player login proofs, only needed if the player requires them
+    #[account(
+        constraint = login_verifier.circuit_type == CircuitType::PlayerLogin @ MyError::VerifierCircuitTypeMismatch,
+        constraint = login_verifier.enabled @ MyError::VerifierDisabled,
+    )]
+    pub login_verifier: Option<Box<Account<'info, VerifierConfig>>>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        constraint = !manager.paused @ MyError::Paused,
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(
+        mut,
+        seeds=[
+            "sol_vault".as_bytes(),
+        ],
+        bump = sol_vault.bump,
+    )]
+    pub sol_vault: Account<'info, SolVault>,
+    system_program: Program<'info, System>,
+}
+
+impl JoinRpsBasicSol<'_> {
+    pub fn join_rps_basic_sol(
+        &mut self,
+        join_game_data: JoinRpsBasicData,
+        player2_limits_bump: u8,
+    ) -> Result<()> {
+        let player2_choice = RpsChoice::try_from(join_game_data.player2_choice)?;
+
+        self.player2.verify_login(
+            self.game_client.key(),
+            self.login_verifier
+                .as_deref()
+                .map(|login_verifier| &**login_verifier),
+            join_game_data.login_proof.as_deref(),
+        )?;
+
+        let now = Clock::get()?.unix_timestamp;
+        self.player2.check_exclusion(now)?;
+        self.player2_limits.bump = player2_limits_bump;
+        self.player2_limits
+            .add_wager(self.rps_basic_game.amount, now)?;
+
+        let amount = self.rps_basic_game.amount;
+        transfer_lamports(
+            &self.player2.to_account_info(),
+            &self.sol_vault.to_account_info(),
+            amount,
+        )?;
+
+        self.game_client.game_opened();
+
+        self.rps_basic_game.join(
+            self.player2.key(),
+            self.game_client.key(),
+            player2_choice,
+            amount,
+        )
+    }
+}
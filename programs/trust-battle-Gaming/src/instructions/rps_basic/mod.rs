@@ -10,5 +10,32 @@ pub use join_rps_basic::*;
 pub mod complete_rps_basic;
 pub use complete_rps_basic::*;
 
+pub mod rematch_rps_basic;
+pub use rematch_rps_basic::*;
+
 pub mod cancel_rps_basic;
 pub use cancel_rps_basic::*;
+
+pub mod claim_timeout_rps_basic;
+pub use claim_timeout_rps_basic::*;
+
+pub mod emergency_refund_rps_basic;
+pub use emergency_refund_rps_basic::*;
+
+pub mod init_rps_basic_sol;
+pub use init_rps_basic_sol::*;
+
+pub mod join_rps_basic_sol;
+pub use join_rps_basic_sol::*;
+
+pub mod complete_rps_basic_sol;
+pub use complete_rps_basic_sol::*;
+
+pub mod cancel_rps_basic_sol;
+pub use cancel_rps_basic_sol::*;
+
+pub mod claim_timeout_rps_basic_sol;
+pub use claim_timeout_rps_basic_sol::*;
+
+pub mod emergency_refund_rps_basic_sol;
+pub use emergency_refund_rps_basic_sol::*;
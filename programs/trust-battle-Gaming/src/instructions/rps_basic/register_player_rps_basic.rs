@@ -1,6 +1,6 @@
 use anchor_lang::prelude::*;
 
-use crate::{GameClient, Player, RpsBasicPlayer};
+use crate::{errors::MyError, GameClient, Player, RpsBasicPlayer};
 
 #[derive(Accounts)]
 pub struct RegisterPlayerRpsBasic<'info> {
@@ -28,10 +28,11 @@ pub struct RegisterPlayerRpsBasic<'info> {
     #[account(
         seeds=[
             "game_client".as_bytes(),
-            &game_client.signer.key().to_bytes()
+            &game_client.seed.to_bytes()
         ],
         bump = game_client.bump,
         has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
     )]
     pub game_client: Account<'info, GameClient>,
     system_program: Program<'info, System>,
@@ -0,0 +1,81 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::MyError, events::RpsBasicRematchPicked, GameClient, Player, RpsBasicGame, RpsChoice,
+    DEFAULT_RPS_BASIC_TIMEOUT,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct RematchRpsBasicData {
+    // Raw choice, validated to reject invalid values with a proper error
+    pub player2_choice: u8,
+}
+
+#[derive(Accounts)]
+pub struct RematchRpsBasic<'info> {
+    #[account(mut)]
+    signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            "rps_basic_game".as_bytes(),
+            rps_basic_game.game_client.key().as_ref(),
+            &rps_basic_game.id.to_le_bytes()
+        ],
+        bump = rps_basic_game.bump,
+        constraint = rps_basic_game.player2.is_some() @ MyError::RpsBasicGameNotJoined,
+        constraint = player2.key() == rps_basic_game.player2.as_ref().unwrap().key @ MyError::RpsBasicPlayer2Mismatch,
+        constraint = rps_basic_game.player2.as_ref().unwrap().choice.is_none() @ MyError::RpsBasicNoRematch,
+    )]
+    pub rps_basic_game: Account<'info, RpsBasicGame>,
+    #[account(
+        seeds=[
+            "player".as_bytes(),
+            player2.username.as_bytes()
+        ],
+        bump = player2.bump,
+    )]
+    pub player2: Account<'info, Player>,
+    // Player2 picks through the game client he joined from
+    #[account(
+        seeds=[
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+        has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
+        constraint = rps_basic_game.player2.as_ref().is_some_and(|player2| player2.game_client == game_client.key()) @ MyError::RpsBasicClientMismatch,
+    )]
+    game_client: Account<'info, GameClient>,
+    system_program: Program<'info, System>,
+}
+
+impl RematchRpsBasic<'_> {
+    pub fn rematch_rps_basic(&mut self, rematch_game_data: RematchRpsBasicData) -> Result<()> {
+        let player2_choice = RpsChoice::try_from(rematch_game_data.player2_choice)?;
+        let game = &mut self.rps_basic_game;
+
+        // Once the timeout passed, player1 can claim the game by forfeit instead
+        let now = Clock::get()?.unix_timestamp;
+        require!(
+            game.timeout.is_some_and(|timeout| now <= timeout),
+            MyError::RpsBasicTimeoutReached
+        );
+
+        if let Some(player2) = game.player2.as_mut() {
+            player2.choice = Some(player2_choice);
+        }
+
+        // Player1 must reveal the new round before the timeout
+        game.timeout = Some(now + DEFAULT_RPS_BASIC_TIMEOUT);
+
+        emit!(RpsBasicRematchPicked {
+            game_client: game.game_client,
+            id: game.id,
+            round: game.round,
+            player2_choice,
+        });
+        Ok(())
+    }
+}
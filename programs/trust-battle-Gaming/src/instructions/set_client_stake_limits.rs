@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::MyError, events::ClientStakeLimitsUpdated, ClientMintConfig, GameClient, MintConfig,
+};
+
+#[derive(Accounts)]
+#[instruction(mint: Pubkey)]
+pub struct SetClientStakeLimits<'info> {
+    #[account(mut)]
+    signer: Signer<'info>,
+    #[account(
+        seeds=[
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+        has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
+    )]
+    pub game_client: Account<'info, GameClient>,
+    #[account(
+        init_if_needed,
+        space= 8 + ClientMintConfig::INIT_SPACE,
+        payer=signer,
+        seeds=[
+            "client_mint_config".as_bytes(),
+            game_client.key().as_ref(),
+            mint.as_ref()
+        ],
+        bump
+    )]
+    pub client_mint_config: Account<'info, ClientMintConfig>,
+    system_program: Program<'info, System>,
+}
+
+impl SetClientStakeLimits<'_> {
+    /// Narrow the stake limits of games created on this client, the mint limits still apply
+    pub fn set_client_stake_limits(
+        &mut self,
+        mint: Pubkey,
+        min_amount: u64,
+        max_amount: u64,
+        bump: u8,
+    ) -> Result<()> {
+        MintConfig::validate_limits(min_amount, max_amount)?;
+
+        self.client_mint_config.set_inner(ClientMintConfig {
+            game_client: self.game_client.key(),
+            mint,
+            min_amount,
+            max_amount,
+            bump,
+        });
+
+        emit!(ClientStakeLimitsUpdated {
+            game_client: self.game_client.key(),
+            mint,
+            min_amount,
+            max_amount,
+        });
+        Ok(())
+    }
+}
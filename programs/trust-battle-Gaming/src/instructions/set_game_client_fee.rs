@@ -0,0 +1,45 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::MyError, events::GameClientFeeUpdated, GameClient, Manager};
+
+#[derive(Accounts)]
+pub struct SetGameClientFee<'info> {
+    admin: Signer<'info>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(
+        mut,
+        seeds=[
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+    )]
+    pub game_client: Account<'info, GameClient>,
+}
+
+impl SetGameClientFee<'_> {
+    /// Set the client fee override, `None` goes back to the global client fee
+    pub fn set_game_client_fee(&mut self, client_fee_bps: Option<u16>) -> Result<()> {
+        if let Some(client_fee_bps) = client_fee_bps {
+            Manager::validate_fees(client_fee_bps, self.manager.platform_fee)?;
+        }
+
+        let game_client = &mut self.game_client;
+
+        emit!(GameClientFeeUpdated {
+            game_client: game_client.key(),
+            old_client_fee: game_client.client_fee,
+            new_client_fee: client_fee_bps,
+        });
+
+        game_client.client_fee = client_fee_bps;
+        Ok(())
+    }
+}
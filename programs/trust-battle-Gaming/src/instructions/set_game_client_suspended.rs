@@ -0,0 +1,37 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::MyError, events::GameClientSuspended, GameClient, Manager};
+
+#[derive(Accounts)]
+pub struct SetGameClientSuspended<'info> {
+    admin: Signer<'info>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(
+        mut,
+        seeds=[
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+    )]
+    pub game_client: Account<'info, GameClient>,
+}
+
+impl SetGameClientSuspended<'_> {
+    pub fn set_game_client_suspended(&mut self, suspended: bool) -> Result<()> {
+        self.game_client.suspended = suspended;
+
+        emit!(GameClientSuspended {
+            game_client: self.game_client.key(),
+            suspended,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,26 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::MyError, events::PausedUpdated, Manager};
+
+#[derive(Accounts)]
+pub struct SetPaused<'info> {
+    admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+}
+
+impl SetPaused<'_> {
+    pub fn set_paused(&mut self, paused: bool) -> Result<()> {
+        self.manager.paused = paused;
+
+        emit!(PausedUpdated { paused });
+        Ok(())
+    }
+}
@@ -0,0 +1,68 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::MyError, events::PlayerExclusionUpdated, CircuitType, GameClient, Player,
+    VerifierConfig,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PlayerExclusionData {
+    // Unix timestamp the player can play again from
+    pub excluded_until: i64,
+    // Player login proof, always required so only the player can change it
+    pub login_proof: Vec<u8>,
+}
+
+#[derive(Accounts)]
+pub struct SetPlayerExclusion<'info> {
+    signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            "player".as_bytes(),
+            player.username.as_bytes()
+        ],
+        bump = player.bump
+    )]
+    pub player: Account<'info, Player>,
+    #[account(
+        seeds=[
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+        has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
+    )]
+    pub game_client: Account<'info, GameClient>,
+    #[account(
+        constraint = login_verifier.circuit_type == CircuitType::PlayerLogin @ MyError::VerifierCircuitTypeMismatch,
+        constraint = login_verifier.enabled @ MyError::VerifierDisabled,
+    )]
+    pub login_verifier: Box<Account<'info, VerifierConfig>>,
+}
+
+impl SetPlayerExclusion<'_> {
+    pub fn set_player_exclusion(
+        &mut self,
+        player_exclusion_data: PlayerExclusionData,
+    ) -> Result<()> {
+        self.player.verify_login_proof(
+            self.game_client.key(),
+            Some(&self.login_verifier),
+            &player_exclusion_data.login_proof,
+        )?;
+
+        let pending_effective_at = self.player.set_exclusion(
+            player_exclusion_data.excluded_until,
+            Clock::get()?.unix_timestamp,
+        );
+
+        emit!(PlayerExclusionUpdated {
+            player: self.player.key(),
+            excluded_until: player_exclusion_data.excluded_until,
+            pending_effective_at,
+        });
+        Ok(())
+    }
+}
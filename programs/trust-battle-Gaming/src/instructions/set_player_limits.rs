@@ -0,0 +1,92 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::MyError, events::PlayerLimitsUpdated, CircuitType, GameClient, Player, PlayerLimits,
+    VerifierConfig,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PlayerLimitsData {
+    // Mint the limits are counted in (`SOL_MINT` for SOL games)
+    pub mint: Pubkey,
+    // `None` removes the limit
+    pub max_stake: Option<u64>,
+    pub max_daily_wager: Option<u64>,
+    // Player login proof, always required so only the player can change it
+    pub login_proof: Vec<u8>,
+}
+
+#[derive(Accounts)]
+#[instruction(player_limits_data: PlayerLimitsData)]
+pub struct SetPlayerLimits<'info> {
+    #[account(mut)]
+    signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            "player".as_bytes(),
+            player.username.as_bytes()
+        ],
+        bump = player.bump
+    )]
+    pub player: Account<'info, Player>,
+    #[account(
+        init_if_needed,
+        space= 8 + PlayerLimits::INIT_SPACE,
+        payer=signer,
+        seeds=[
+            "player_limits".as_bytes(),
+            player.key().as_ref(),
+            player_limits_data.mint.as_ref()
+        ],
+        bump
+    )]
+    pub player_limits: Account<'info, PlayerLimits>,
+    #[account(
+        seeds=[
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+        has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
+    )]
+    pub game_client: Account<'info, GameClient>,
+    #[account(
+        constraint = login_verifier.circuit_type == CircuitType::PlayerLogin @ MyError::VerifierCircuitTypeMismatch,
+        constraint = login_verifier.enabled @ MyError::VerifierDisabled,
+    )]
+    pub login_verifier: Box<Account<'info, VerifierConfig>>,
+    system_program: Program<'info, System>,
+}
+
+impl SetPlayerLimits<'_> {
+    pub fn set_player_limits(
+        &mut self,
+        player_limits_data: PlayerLimitsData,
+        player_limits_bump: u8,
+    ) -> Result<()> {
+        self.player.verify_login_proof(
+            self.game_client.key(),
+            Some(&self.login_verifier),
+            &player_limits_data.login_proof,
+        )?;
+
+        self.player_limits.bump = player_limits_bump;
+
+        let pending_effective_at = self.player_limits.set_limits(
+            player_limits_data.max_stake,
+            player_limits_data.max_daily_wager,
+            Clock::get()?.unix_timestamp,
+        );
+
+        emit!(PlayerLimitsUpdated {
+            player: self.player.key(),
+            mint: player_limits_data.mint,
+            max_stake: player_limits_data.max_stake,
+            max_daily_wager: player_limits_data.max_daily_wager,
+            pending_effective_at,
+        });
+        Ok(())
+    }
+}
@@ -0,0 +1,64 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::MyError, events::PlayerLoginProofUpdated, CircuitType, GameClient, Player,
+    VerifierConfig,
+};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct PlayerLoginProofData {
+    // Require a login proof before game clients can spend player funds
+    pub require_login_proof: bool,
+    // Player login proof, always required so only the player can change it
+    pub login_proof: Vec<u8>,
+}
+
+#[derive(Accounts)]
+pub struct SetPlayerLoginProof<'info> {
+    signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            "player".as_bytes(),
+            player.username.as_bytes()
+        ],
+        bump = player.bump
+    )]
+    pub player: Account<'info, Player>,
+    #[account(
+        seeds=[
+            "game_client".as_bytes(),
+            &game_client.seed.to_bytes()
+        ],
+        bump = game_client.bump,
+        has_one = signer,
+        constraint = !game_client.suspended @ MyError::GameClientSuspended,
+    )]
+    pub game_client: Account<'info, GameClient>,
+    #[account(
+        constraint = login_verifier.circuit_type == CircuitType::PlayerLogin @ MyError::VerifierCircuitTypeMismatch,
+        constraint = login_verifier.enabled @ MyError::VerifierDisabled,
+    )]
+    pub login_verifier: Box<Account<'info, VerifierConfig>>,
+}
+
+impl SetPlayerLoginProof<'_> {
+    pub fn set_player_login_proof(
+        &mut self,
+        player_login_proof_data: PlayerLoginProofData,
+    ) -> Result<()> {
+        self.player.verify_login_proof(
+            self.game_client.key(),
+            Some(&self.login_verifier),
+            &player_login_proof_data.login_proof,
+        )?;
+
+        self.player.require_login_proof = player_login_proof_data.require_login_proof;
+
+        emit!(PlayerLoginProofUpdated {
+            player: self.player.key(),
+            require_login_proof: player_login_proof_data.require_login_proof,
+        });
+        Ok(())
+    }
+}
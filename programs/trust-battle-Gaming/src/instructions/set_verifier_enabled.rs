@@ -0,0 +1,40 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::MyError, events::VerifierUpdated, Manager, VerifierConfig};
+
+#[derive(Accounts)]
+pub struct SetVerifierEnabled<'info> {
+    admin: Signer<'info>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(
+        mut,
+        seeds=[
+            "verifier_config".as_bytes(),
+            &[verifier_config.circuit_type as u8],
+            &verifier_config.version.to_le_bytes()
+        ],
+        bump = verifier_config.bump,
+    )]
+    pub verifier_config: Account<'info, VerifierConfig>,
+}
+
+impl SetVerifierEnabled<'_> {
+    pub fn set_verifier_enabled(&mut self, enabled: bool) -> Result<()> {
+        self.verifier_config.enabled = enabled;
+
+        emit!(VerifierUpdated {
+            verifier_config: self.verifier_config.key(),
+            circuit_type: self.verifier_config.circuit_type,
+            version: self.verifier_config.version,
+            enabled,
+        });
+        Ok(())
+    }
+}
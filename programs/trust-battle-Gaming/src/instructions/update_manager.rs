@@ -0,0 +1,55 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::MyError, events::ManagerUpdated, Manager};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateManagerData {
+    // BPS of client fee
+    pub client_fee_bps: u16,
+    // BPS of platform fee
+    pub platform_fee_bps: u16,
+    // BPS of the client fee that goes to the joining client
+    pub join_client_share_bps: u16,
+    // Pubkey of platform (where to send fee)
+    pub platform_key: Pubkey,
+}
+
+#[derive(Accounts)]
+pub struct UpdateManager<'info> {
+    admin: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+}
+
+impl UpdateManager<'_> {
+    pub fn update_manager(&mut self, update_data: UpdateManagerData) -> Result<()> {
+        Manager::validate_fees(update_data.client_fee_bps, update_data.platform_fee_bps)?;
+        Manager::validate_join_client_share(update_data.join_client_share_bps)?;
+
+        let manager = &mut self.manager;
+
+        emit!(ManagerUpdated {
+            old_client_fee: manager.client_fee,
+            new_client_fee: update_data.client_fee_bps,
+            old_platform_fee: manager.platform_fee,
+            new_platform_fee: update_data.platform_fee_bps,
+            old_join_client_share: manager.join_client_share,
+            new_join_client_share: update_data.join_client_share_bps,
+            old_platform_key: manager.platform_key,
+            new_platform_key: update_data.platform_key,
+        });
+
+        manager.client_fee = update_data.client_fee_bps;
+        manager.platform_fee = update_data.platform_fee_bps;
+        manager.join_client_share = update_data.join_client_share_bps;
+        manager.platform_key = update_data.platform_key;
+        Ok(())
+    }
+}
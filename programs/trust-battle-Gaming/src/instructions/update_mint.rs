@@ -0,0 +1,54 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::MyError, events::MintUpdated, Manager, MintConfig};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct UpdateMintData {
+    pub min_amount: u64,
+    pub max_amount: u64,
+    pub enabled: bool,
+}
+
+#[derive(Accounts)]
+pub struct UpdateMint<'info> {
+    admin: Signer<'info>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump,
+        has_one = admin @ MyError::SignerMustBeAdmin,
+    )]
+    pub manager: Account<'info, Manager>,
+    #[account(
+        mut,
+        seeds=[
+            "mint_config".as_bytes(),
+            mint_config.mint.as_ref()
+        ],
+        bump = mint_config.bump,
+    )]
+    pub mint_config: Account<'info, MintConfig>,
+}
+
+impl UpdateMint<'_> {
+    pub fn update_mint(&mut self, update_mint_data: UpdateMintData) -> Result<()> {
+        MintConfig::validate_limits(update_mint_data.min_amount, update_mint_data.max_amount)?;
+
+        let mint_config = &mut self.mint_config;
+
+        emit!(MintUpdated {
+            mint: mint_config.mint,
+            old_min_amount: mint_config.min_amount,
+            new_min_amount: update_mint_data.min_amount,
+            old_max_amount: mint_config.max_amount,
+            new_max_amount: update_mint_data.max_amount,
+            enabled: update_mint_data.enabled,
+        });
+
+        mint_config.min_amount = update_mint_data.min_amount;
+        mint_config.max_amount = update_mint_data.max_amount;
+        mint_config.enabled = update_mint_data.enabled;
+        Ok(())
+    }
+}
@@ -0,0 +1,85 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token_interface::{
+    transfer_checked, Mint, TokenAccount, TokenInterface, TransferChecked,
+};
+
+use crate::{errors::MyError, CircuitType, Manager, Player, VerifierConfig};
+
+#[derive(AnchorSerialize, AnchorDeserialize)]
+pub struct WithdrawPlayerData {
+    pub proof: Vec<u8>,
+    pub amount: u64,
+}
+
+#[derive(Accounts)]
+pub struct WithdrawPlayer<'info> {
+    #[account(mut)]
+    signer: Signer<'info>,
+    #[account(
+        mut,
+        seeds=[
+            "player".as_bytes(),
+            player.username.as_bytes()
+        ],
+        bump = player.bump
+    )]
+    pub player: Account<'info, Player>,
+    #[account(
+        mut,
+        associated_token::mint = mint,
+        associated_token::authority = player,
+        associated_token::token_program = token_program
+    )]
+    pub player_ata: InterfaceAccount<'info, TokenAccount>,
+    // Any token account the player proved he wants to withdraw to
+    #[account(
+        mut,
+        token::mint = mint,
+        token::token_program = token_program,
+    )]
+    pub destination: InterfaceAccount<'info, TokenAccount>,
+    #[account(
+        seeds=[
+            "manager".as_bytes(),
+        ],
+        bump = manager.bump
+    )]
+    pub manager: Account<'info, Manager>,
+    pub mint: InterfaceAccount<'info, Mint>,
+    pub token_program: Interface<'info, TokenInterface>,
+    // Verifier of player withdraw proofs
+    #[account(
+        constraint = withdraw_verifier.circuit_type == CircuitType::PlayerWithdraw @ MyError::VerifierCircuitTypeMismatch,
+        constraint = withdraw_verifier.enabled @ MyError::VerifierDisabled,
+    )]
+    pub withdraw_verifier: Box<Account<'info, VerifierConfig>>,
+    system_program: Program<'info, System>,
+}
+
+impl WithdrawPlayer<'_> {
+    pub fn withdraw_player(&mut self, withdraw_player_data: WithdrawPlayerData) -> Result<()> {
+        self.player.verify_withdraw(
+            &self.withdraw_verifier,
+            self.destination.key(),
+            withdraw_player_data.amount,
+            &withdraw_player_data.proof,
+        )?;
+
+        let player_pda_seeds = &[
+            "player".as_bytes(),
+            self.player.username.as_bytes(),
+            &[self.player.bump],
+        ];
+        let player_pda_seeds = &[&player_pda_seeds[..]];
+
+        let cpi_accounts = TransferChecked {
+            mint: self.mint.to_account_info(),
+            from: self.player_ata.to_account_info(),
+            to: self.destination.to_account_info(),
+            authority: self.player.to_account_info(),
+        };
+        let cpi_program = self.token_program.to_account_info();
+        let cpi_context = CpiContext::new_with_signer(cpi_program, cpi_accounts, player_pda_seeds);
+        transfer_checked(cpi_context, withdraw_player_data.amount, self.mint.decimals)
+    }
+}
@@ -0,0 +1,48 @@
+use anchor_lang::prelude::*;
+
+use crate::{
+    errors::MyError, transfer_lamports, CircuitType, Player, VerifierConfig, WithdrawPlayerData,
+};
+
+#[derive(Accounts)]
+pub struct WithdrawPlayerSol<'info> {
+    #[account(mut)]
+    signer: Signer<'info>,
+    // SOL winnings are held by the player account itself
+    #[account(
+        mut,
+        seeds=[
+            "player".as_bytes(),
+            player.username.as_bytes()
+        ],
+        bump = player.bump
+    )]
+    pub player: Account<'info, Player>,
+    // Any wallet the player proved he wants to withdraw to
+    #[account(mut)]
+    pub destination: SystemAccount<'info>,
+    // Verifier of player withdraw proofs
+    #[account(
+        constraint = withdraw_verifier.circuit_type == CircuitType::PlayerWithdraw @ MyError::VerifierCircuitTypeMismatch,
+        constraint = withdraw_verifier.enabled @ MyError::VerifierDisabled,
+    )]
+    pub withdraw_verifier: Box<Account<'info, VerifierConfig>>,
+    system_program: Program<'info, System>,
+}
+
+impl WithdrawPlayerSol<'_> {
+    pub fn withdraw_player_sol(&mut self, withdraw_player_data: WithdrawPlayerData) -> Result<()> {
+        self.player.verify_withdraw(
+            &self.withdraw_verifier,
+            self.destination.key(),
+            withdraw_player_data.amount,
+            &withdraw_player_data.proof,
+        )?;
+
+        transfer_lamports(
+            &self.player.to_account_info(),
+            &self.destination.to_account_info(),
+            withdraw_player_data.amount,
+        )
+    }
+}
@@ -2,21 +2,34 @@
 use anchor_lang::prelude::*;
 
 pub mod errors;
+pub mod events;
 
 pub mod instructions;
 pub mod state;
 
+use events::{GameClientRegistered, PlayerRegistered, RpsBasicPlayerRegistered};
 pub use instructions::*;
 pub use state::*;
 
 declare_id!("BkGSQDjUxXzLrzfDoyMyURuVwfBc8p7AYaFE1wnQwrMu");
 
-// Replace with admin pubkey
+// Replace with admin pubkey, only used to init the manager
+// after that the admin is stored in `Manager` and can be transferred
 const ADMIN_PUBKEY: Pubkey = pubkey!("3xoJZkhxuzKpKATL7UhskTA17uBuEnMeuLAqhovETHg4");
 
 const DEFAULT_RPS_BASIC_TIMEOUT: i64 = 2629800;
 
-// verifying key of our rps basic program
+// Cooldown before raised player limits (or a shorter self-exclusion) apply
+const PLAYER_LIMITS_COOLDOWN: i64 = 86400;
+
+// Player daily wager limit is counted over the last 24 of these hourly buckets
+const PLAYER_WAGER_BUCKET: i64 = 3600;
+
+// Mint recorded on games played with native SOL, no token mint can have this address
+pub const SOL_MINT: Pubkey = Pubkey::new_from_array([0; 32]);
+
+// verifying key of our rps basic program, registered as the first RPS verifier on init,
+// later versions are registered with `register_verifier`
 const VK_RPS_BASIC_COMPLETE: &str =
     "0x00c4cf1292d6730be2cfdebe7a064a26bd09db12a6f5a547a46db8e72c72acd8";
 
@@ -25,32 +38,194 @@ pub mod trust_battle_gaming_solana {
     use super::*;
 
     pub fn init(ctx: Context<Init>, init_data: InitData) -> Result<()> {
-        ctx.accounts.init(init_data, ctx.bumps.manager)
+        ctx.accounts.init(
+            init_data,
+            ctx.bumps.manager,
+            ctx.bumps.mint_config,
+            ctx.bumps.rps_basic_verifier,
+        )
+    }
+
+    pub fn propose_admin(ctx: Context<ProposeAdmin>, new_admin: Pubkey) -> Result<()> {
+        ctx.accounts.propose_admin(new_admin)
+    }
+
+    pub fn accept_admin(ctx: Context<AcceptAdmin>) -> Result<()> {
+        ctx.accounts.accept_admin()
+    }
+
+    pub fn update_manager(
+        ctx: Context<UpdateManager>,
+        update_manager_data: UpdateManagerData,
+    ) -> Result<()> {
+        ctx.accounts.update_manager(update_manager_data)
+    }
+
+    pub fn set_paused(ctx: Context<SetPaused>, paused: bool) -> Result<()> {
+        ctx.accounts.set_paused(paused)
+    }
+
+    pub fn register_mint(
+        ctx: Context<RegisterMint>,
+        min_amount: u64,
+        max_amount: u64,
+    ) -> Result<()> {
+        ctx.accounts
+            .register_mint(min_amount, max_amount, ctx.bumps.mint_config)
+    }
+
+    pub fn update_mint(ctx: Context<UpdateMint>, update_mint_data: UpdateMintData) -> Result<()> {
+        ctx.accounts.update_mint(update_mint_data)
+    }
+
+    pub fn register_sol(ctx: Context<RegisterSol>, min_amount: u64, max_amount: u64) -> Result<()> {
+        ctx.accounts.register_sol(
+            min_amount,
+            max_amount,
+            ctx.bumps.mint_config,
+            ctx.bumps.sol_vault,
+        )
+    }
+
+    pub fn register_verifier(
+        ctx: Context<RegisterVerifier>,
+        verifier_data: RegisterVerifierData,
+    ) -> Result<()> {
+        ctx.accounts
+            .register_verifier(verifier_data, ctx.bumps.verifier_config)
+    }
+
+    pub fn set_verifier_enabled(ctx: Context<SetVerifierEnabled>, enabled: bool) -> Result<()> {
+        ctx.accounts.set_verifier_enabled(enabled)
     }
 
     pub fn register_game_client(
         ctx: Context<RegisterGameClient>,
         game_client_data: GameClientData,
     ) -> Result<()> {
+        emit!(GameClientRegistered {
+            game_client: ctx.accounts.game_client.key(),
+            name: game_client_data.name.clone(),
+            signer: game_client_data.signer,
+        });
+
         ctx.accounts.game_client.set_inner(GameClient {
             name: game_client_data.name,
             signer: game_client_data.signer,
+            seed: game_client_data.signer,
+            suspended: false,
+            client_fee: None,
+            open_games: 0,
+            game_count: 0,
             bump: ctx.bumps.game_client,
         });
         Ok(())
     }
 
+    pub fn set_game_client_suspended(
+        ctx: Context<SetGameClientSuspended>,
+        suspended: bool,
+    ) -> Result<()> {
+        ctx.accounts.set_game_client_suspended(suspended)
+    }
+
+    pub fn migrate_game_client_signer(
+        ctx: Context<MigrateGameClientSigner>,
+        new_signer: Pubkey,
+    ) -> Result<()> {
+        ctx.accounts.migrate_game_client_signer(new_signer)
+    }
+
+    pub fn set_game_client_fee(
+        ctx: Context<SetGameClientFee>,
+        client_fee_bps: Option<u16>,
+    ) -> Result<()> {
+        ctx.accounts.set_game_client_fee(client_fee_bps)
+    }
+
+    pub fn set_client_stake_limits(
+        ctx: Context<SetClientStakeLimits>,
+        mint: Pubkey,
+        min_amount: u64,
+        max_amount: u64,
+    ) -> Result<()> {
+        ctx.accounts.set_client_stake_limits(
+            mint,
+            min_amount,
+            max_amount,
+            ctx.bumps.client_mint_config,
+        )
+    }
+
+    pub fn close_game_client(ctx: Context<CloseGameClient>) -> Result<()> {
+        ctx.accounts.close_game_client()
+    }
+
     pub fn register_player(ctx: Context<RegisterPlayer>, player_data: PlayerData) -> Result<()> {
+        emit!(PlayerRegistered {
+            player: ctx.accounts.player.key(),
+            username: player_data.username.clone(),
+            game_client: ctx.accounts.game_client.key(),
+        });
+
         ctx.accounts.player.set_inner(Player {
             username: player_data.username,
             login_hash: player_data.login_hash,
+            nonce: 0,
+            // Opt-in: until the player turns it on with `set_player_login_proof`,
+            // game clients can stake player funds without a login proof
+            require_login_proof: false,
+            excluded_until: 0,
+            pending_exclusion: None,
             bump: ctx.bumps.player,
         });
         Ok(())
     }
 
+    pub fn withdraw_player(
+        ctx: Context<WithdrawPlayer>,
+        withdraw_player_data: WithdrawPlayerData,
+    ) -> Result<()> {
+        ctx.accounts.withdraw_player(withdraw_player_data)
+    }
+
+    pub fn set_player_login_proof(
+        ctx: Context<SetPlayerLoginProof>,
+        player_login_proof_data: PlayerLoginProofData,
+    ) -> Result<()> {
+        ctx.accounts.set_player_login_proof(player_login_proof_data)
+    }
+
+    pub fn set_player_limits(
+        ctx: Context<SetPlayerLimits>,
+        player_limits_data: PlayerLimitsData,
+    ) -> Result<()> {
+        ctx.accounts
+            .set_player_limits(player_limits_data, ctx.bumps.player_limits)
+    }
+
+    pub fn set_player_exclusion(
+        ctx: Context<SetPlayerExclusion>,
+        player_exclusion_data: PlayerExclusionData,
+    ) -> Result<()> {
+        ctx.accounts.set_player_exclusion(player_exclusion_data)
+    }
+
+    pub fn withdraw_player_sol(
+        ctx: Context<WithdrawPlayerSol>,
+        withdraw_player_data: WithdrawPlayerData,
+    ) -> Result<()> {
+        ctx.accounts.withdraw_player_sol(withdraw_player_data)
+    }
+
     pub fn register_player_rps_basic(ctx: Context<RegisterPlayerRpsBasic>) -> Result<()> {
+        emit!(RpsBasicPlayerRegistered {
+            player: ctx.accounts.player.key(),
+            game_client: ctx.accounts.game_client.key(),
+        });
+
         ctx.accounts.player_rps_basic.set_inner(RpsBasicPlayer {
+            rating: RPS_BASIC_DEFAULT_RATING,
             bump: ctx.bumps.player_rps_basic,
             ..Default::default()
         });
@@ -61,25 +236,84 @@ pub mod trust_battle_gaming_solana {
         ctx: Context<InitRpsBasic>,
         init_rps_basic_data: InitRpsBasicData,
     ) -> Result<()> {
-        ctx.accounts
-            .init_rps_basic(init_rps_basic_data, ctx.bumps.rps_basic_game)
+        ctx.accounts.init_rps_basic(
+            init_rps_basic_data,
+            ctx.bumps.rps_basic_game,
+            ctx.bumps.player1_limits,
+        )
     }
 
     pub fn join_rps_basic(
         ctx: Context<JoinRpsBasic>,
         join_rps_basic_data: JoinRpsBasicData,
     ) -> Result<()> {
-        ctx.accounts.join_rps_basic(join_rps_basic_data)
+        ctx.accounts
+            .join_rps_basic(join_rps_basic_data, ctx.bumps.player2_limits)
     }
 
     pub fn complete_rps_basic(
         ctx: Context<CompleteRpsBasic>,
         complete_game_data: CompleteRpsBasicData,
     ) -> Result<()> {
-        ctx.accounts.complete_rps_basic(complete_game_data)
+        ctx.accounts
+            .complete_rps_basic(complete_game_data, ctx.bumps.rps_basic_result)
+    }
+
+    pub fn rematch_rps_basic(
+        ctx: Context<RematchRpsBasic>,
+        rematch_rps_basic_data: RematchRpsBasicData,
+    ) -> Result<()> {
+        ctx.accounts.rematch_rps_basic(rematch_rps_basic_data)
     }
 
     pub fn cancel_rps_basic(ctx: Context<CancelRpsBasic>) -> Result<()> {
         ctx.accounts.cancel_rps_basic()
     }
+
+    pub fn claim_timeout_rps_basic(ctx: Context<ClaimTimeoutRpsBasic>) -> Result<()> {
+        ctx.accounts.claim_timeout_rps_basic()
+    }
+
+    pub fn emergency_refund_rps_basic(ctx: Context<EmergencyRefundRpsBasic>) -> Result<()> {
+        ctx.accounts.emergency_refund_rps_basic()
+    }
+
+    pub fn init_rps_basic_sol(
+        ctx: Context<InitRpsBasicSol>,
+        init_rps_basic_data: InitRpsBasicData,
+    ) -> Result<()> {
+        ctx.accounts.init_rps_basic_sol(
+            init_rps_basic_data,
+            ctx.bumps.rps_basic_game,
+            ctx.bumps.player1_limits,
+        )
+    }
+
+    pub fn join_rps_basic_sol(
+        ctx: Context<JoinRpsBasicSol>,
+        join_rps_basic_data: JoinRpsBasicData,
+    ) -> Result<()> {
+        ctx.accounts
+            .join_rps_basic_sol(join_rps_basic_data, ctx.bumps.player2_limits)
+    }
+
+    pub fn complete_rps_basic_sol(
+        ctx: Context<CompleteRpsBasicSol>,
+        complete_game_data: CompleteRpsBasicData,
+    ) -> Result<()> {
+        ctx.accounts
+            .complete_rps_basic_sol(complete_game_data, ctx.bumps.rps_basic_result)
+    }
+
+    pub fn cancel_rps_basic_sol(ctx: Context<CancelRpsBasicSol>) -> Result<()> {
+        ctx.accounts.cancel_rps_basic_sol()
+    }
+
+    pub fn claim_timeout_rps_basic_sol(ctx: Context<ClaimTimeoutRpsBasicSol>) -> Result<()> {
+        ctx.accounts.claim_timeout_rps_basic_sol()
+    }
+
+    pub fn emergency_refund_rps_basic_sol(ctx: Context<EmergencyRefundRpsBasicSol>) -> Result<()> {
+        ctx.accounts.emergency_refund_rps_basic_sol()
+    }
 }
@@ -1,10 +1,55 @@
 use anchor_lang::prelude::*;
 
+use crate::Manager;
+
 #[account]
 #[derive(InitSpace)]
 pub struct GameClient {
     #[max_len(260)]
     pub name: String,
     pub signer: Pubkey,
+    // Signer the PDA was derived from, stays the same when the signer is migrated
+    pub seed: Pubkey,
+    // Suspended clients can't act on behalf of players, other clients can still
+    // complete their running games and the admin refunds the unjoined ones
+    pub suspended: bool,
+    // Negotiated client fee BPS, overrides `Manager.client_fee` when set
+    pub client_fee: Option<u16>,
+    // Id of the next game created on this client
+    pub game_count: u64,
+    // Games created on or joined from this client that are not settled yet
+    pub open_games: u32,
     pub bump: u8,
 }
+
+impl GameClient {
+    /// Client fee BPS of this game client, falls back to the global client fee
+    pub fn client_fee(&self, manager: &Manager) -> u16 {
+        self.client_fee.unwrap_or(manager.client_fee)
+    }
+
+    /// Game was created on or joined from this client
+    pub fn game_opened(&mut self) {
+        self.open_games += 1;
+    }
+
+    /// Unjoined game was cancelled on the client it was created on
+    pub fn game_closed(&mut self) {
+        self.open_games -= 1;
+    }
+
+    /// Joined game was settled, origin and join client can be the same account
+    pub fn game_settled(
+        origin_client: &mut Account<GameClient>,
+        join_client: &mut Account<GameClient>,
+    ) {
+        origin_client.game_closed();
+        if origin_client.key() == join_client.key() {
+            // Both copies are written back, so they must end up with the same count
+            origin_client.game_closed();
+            join_client.open_games = origin_client.open_games;
+        } else {
+            join_client.game_closed();
+        }
+    }
+}
@@ -1,11 +1,35 @@
 use anchor_lang::prelude::*;
 
+use crate::errors::MyError;
+
 #[account]
 #[derive(InitSpace)]
 pub struct Manager {
+    pub admin: Pubkey,
+    // Proposed admin, becomes admin once he accepts
+    pub pending_admin: Option<Pubkey>,
     pub client_fee: u16,
     pub platform_fee: u16,
-    pub usdc_mint: Pubkey,
+    // BPS of the client fee that goes to the joining client in cross-client games
+    pub join_client_share: u16,
     pub platform_key: Pubkey,
+    // Blocks new players and games, settlement and refunds keep working
+    pub paused: bool,
     pub bump: u8,
 }
+
+impl Manager {
+    /// Fees are taken from the same amount, so together they must stay below 100%
+    pub fn validate_fees(client_fee: u16, platform_fee: u16) -> Result<()> {
+        require!(
+            (client_fee as u32) + (platform_fee as u32) < 10000,
+            MyError::InvalidFeeBps
+        );
+        Ok(())
+    }
+
+    pub fn validate_join_client_share(join_client_share: u16) -> Result<()> {
+        require!(join_client_share <= 10000, MyError::InvalidShareBps);
+        Ok(())
+    }
+}
@@ -0,0 +1,63 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MyError;
+
+/// Token allowed for wagers, registered by the admin
+#[account]
+#[derive(InitSpace)]
+pub struct MintConfig {
+    pub mint: Pubkey,
+    // Stake limits of a game in this token
+    pub min_amount: u64,
+    pub max_amount: u64,
+    // Disabled mints can't be used for new games, running games still settle
+    pub enabled: bool,
+    pub bump: u8,
+}
+
+/// Stake limits a game client sets for its own games, within the mint limits
+#[account]
+#[derive(InitSpace)]
+pub struct ClientMintConfig {
+    pub game_client: Pubkey,
+    pub mint: Pubkey,
+    pub min_amount: u64,
+    pub max_amount: u64,
+    pub bump: u8,
+}
+
+impl ClientMintConfig {
+    /// Client stake limits from their PDA, `None` until the client sets them
+    pub fn load(account: &AccountInfo) -> Result<Option<Self>> {
+        if account.owner != &crate::ID || account.data_is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(Self::try_deserialize(
+            &mut &account.try_borrow_data()?[..],
+        )?))
+    }
+}
+
+impl MintConfig {
+    pub fn validate_limits(min_amount: u64, max_amount: u64) -> Result<()> {
+        require!(min_amount <= max_amount, MyError::InvalidStakeLimits);
+        Ok(())
+    }
+
+    /// Check the stake against the mint limits, narrowed by the client limits if set
+    pub fn check_amount(
+        &self,
+        amount: u64,
+        client_mint_config: Option<&ClientMintConfig>,
+    ) -> Result<()> {
+        let (mut min_amount, mut max_amount) = (self.min_amount, self.max_amount);
+        if let Some(client_mint_config) = client_mint_config {
+            min_amount = min_amount.max(client_mint_config.min_amount);
+            max_amount = max_amount.min(client_mint_config.max_amount);
+        }
+
+        require!(amount >= min_amount, MyError::RpsBasicAmountTooLow);
+        require!(amount <= max_amount, MyError::RpsBasicAmountTooHigh);
+        Ok(())
+    }
+}
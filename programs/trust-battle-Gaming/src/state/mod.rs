@@ -1,11 +1,23 @@
 pub mod manager;
 pub use manager::*;
 
+pub mod mint_config;
+pub use mint_config::*;
+
+pub mod sol_vault;
+pub use sol_vault::*;
+
+pub mod verifier_config;
+pub use verifier_config::*;
+
 pub mod game_client;
 pub use game_client::*;
 
 pub mod player;
 pub use player::*;
 
+pub mod player_limits;
+pub use player_limits::*;
+
 pub mod rps_basic_state;
 pub use rps_basic_state::*;
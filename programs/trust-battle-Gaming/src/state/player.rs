@@ -1,10 +1,245 @@
 use anchor_lang::prelude::*;
 
+use crate::{errors::MyError, VerifierConfig, PLAYER_LIMITS_COOLDOWN};
+
+/// Shorter self-exclusion, only applied once the cooldown is over
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct PendingExclusion {
+    pub excluded_until: i64,
+    pub effective_at: i64,
+}
+
 #[account]
 #[derive(InitSpace)]
 pub struct Player {
     #[max_len(260)]
     pub username: String,
     pub login_hash: [u8; 32],
+    // Part of every player proof, increased after each use to prevent replay
+    pub nonce: u64,
+    // Game clients must provide a login proof before spending player funds,
+    // only changed with a login proof
+    pub require_login_proof: bool,
+    // Player can't start or join games until this time
+    pub excluded_until: i64,
+    pub pending_exclusion: Option<PendingExclusion>,
     pub bump: u8,
 }
+
+impl Player {
+    /// Verify the player login proof for the given game client.
+    ///
+    /// Players that don't require a login proof are accepted without one.
+    pub fn verify_login(
+        &mut self,
+        game_client: Pubkey,
+        login_verifier: Option<&VerifierConfig>,
+        login_proof: Option<&[u8]>,
+    ) -> Result<()> {
+        if !self.require_login_proof {
+            return Ok(());
+        }
+
+        let login_proof = login_proof.ok_or(MyError::PlayerLoginProofRequired)?;
+        self.verify_login_proof(game_client, login_verifier, login_proof)
+    }
+
+    /// Verify the player login proof for the given game client, whatever the player settings
+    pub fn verify_login_proof(
+        &mut self,
+        game_client: Pubkey,
+        login_verifier: Option<&VerifierConfig>,
+        login_proof: &[u8],
+    ) -> Result<()> {
+        let login_verifier = login_verifier.ok_or(MyError::PlayerLoginVerifierRequired)?;
+
+        // Get public input for verification
+        let public_inputs: Vec<u8> = PlayerLoginPublic {
+            login_hash: self.login_hash,
+            client_pubkey: game_client,
+            nonce: self.nonce,
+        }
+        .into();
+
+        // Verify proof
+        login_verifier
+            .verify_proof(login_proof, &public_inputs)
+            .map_err(|x| {
+                msg!("{:?}", x);
+                MyError::PlayerProofVerify
+            })?;
+
+        // Proof can't be used again
+        self.nonce += 1;
+        Ok(())
+    }
+
+    /// Verify the player withdraw proof, the player proves both the destination and the amount
+    pub fn verify_withdraw(
+        &mut self,
+        withdraw_verifier: &VerifierConfig,
+        destination: Pubkey,
+        amount: u64,
+        withdraw_proof: &[u8],
+    ) -> Result<()> {
+        // Get public input for verification
+        let public_inputs: Vec<u8> = PlayerWithdrawPublic {
+            login_hash: self.login_hash,
+            nonce: self.nonce,
+            destination,
+            amount,
+        }
+        .into();
+
+        // Verify proof
+        withdraw_verifier
+            .verify_proof(withdraw_proof, &public_inputs)
+            .map_err(|x| {
+                msg!("{:?}", x);
+                MyError::PlayerProofVerify
+            })?;
+
+        // Proof can't be used again
+        self.nonce += 1;
+        Ok(())
+    }
+
+    /// Longer self-exclusion applies right away, a shorter one waits for the cooldown
+    pub fn set_exclusion(&mut self, excluded_until: i64, now: i64) -> Option<i64> {
+        self.apply_pending_exclusion(now);
+
+        if excluded_until >= self.excluded_until {
+            self.excluded_until = excluded_until;
+            self.pending_exclusion = None;
+        } else {
+            self.pending_exclusion = Some(PendingExclusion {
+                excluded_until,
+                effective_at: now + PLAYER_LIMITS_COOLDOWN,
+            });
+        }
+        self.pending_exclusion
+            .map(|pending_exclusion| pending_exclusion.effective_at)
+    }
+
+    pub fn check_exclusion(&mut self, now: i64) -> Result<()> {
+        self.apply_pending_exclusion(now);
+        require!(now >= self.excluded_until, MyError::PlayerSelfExcluded);
+        Ok(())
+    }
+
+    fn apply_pending_exclusion(&mut self, now: i64) {
+        if let Some(pending_exclusion) = self
+            .pending_exclusion
+            .filter(|pending_exclusion| now >= pending_exclusion.effective_at)
+        {
+            self.excluded_until = pending_exclusion.excluded_until;
+            self.pending_exclusion = None;
+        }
+    }
+}
+
+/// Public inputs of the player login proof
+pub struct PlayerLoginPublic {
+    pub login_hash: [u8; 32],
+    pub client_pubkey: Pubkey,
+    pub nonce: u64,
+}
+
+impl From<PlayerLoginPublic> for Vec<u8> {
+    fn from(value: PlayerLoginPublic) -> Self {
+        [
+            &value.login_hash[..],
+            &value.client_pubkey.to_bytes(),
+            &value.nonce.to_le_bytes(),
+        ]
+        .concat()
+    }
+}
+
+/// Public inputs of the player withdraw proof
+pub struct PlayerWithdrawPublic {
+    pub login_hash: [u8; 32],
+    pub nonce: u64,
+    pub destination: Pubkey,
+    pub amount: u64,
+}
+
+impl From<PlayerWithdrawPublic> for Vec<u8> {
+    fn from(value: PlayerWithdrawPublic) -> Self {
+        [
+            &value.login_hash[..],
+            &value.nonce.to_le_bytes(),
+            &value.destination.to_bytes(),
+            &value.amount.to_le_bytes(),
+        ]
+        .concat()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn player(require_login_proof: bool) -> Player {
+        Player {
+            username: "player1".to_string(),
+            login_hash: [1; 32],
+            nonce: 0,
+            require_login_proof,
+            excluded_until: 0,
+            pending_exclusion: None,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn login_proof_is_optional_unless_required() {
+        let mut player = player(false);
+        assert!(player.verify_login(Pubkey::default(), None, None).is_ok());
+        assert_eq!(player.nonce, 0);
+    }
+
+    #[test]
+    fn required_login_proof_must_be_provided() {
+        let mut player = player(true);
+        assert_eq!(
+            player.verify_login(Pubkey::default(), None, None),
+            Err(MyError::PlayerLoginProofRequired.into())
+        );
+        assert_eq!(
+            player.verify_login(Pubkey::default(), None, Some(&[1; 32])),
+            Err(MyError::PlayerLoginVerifierRequired.into())
+        );
+        assert_eq!(player.nonce, 0);
+    }
+
+    #[test]
+    fn longer_exclusion_applies_right_away() {
+        let mut player = player(false);
+        let now = 1_700_000_000;
+        assert_eq!(player.set_exclusion(now + 3600, now), None);
+        assert_eq!(
+            player.check_exclusion(now),
+            Err(MyError::PlayerSelfExcluded.into())
+        );
+        assert!(player.check_exclusion(now + 3600).is_ok());
+    }
+
+    #[test]
+    fn shorter_exclusion_waits_for_the_cooldown() {
+        let mut player = player(false);
+        let now = 1_700_000_000;
+        player.set_exclusion(now + 7 * PLAYER_LIMITS_COOLDOWN, now);
+
+        assert_eq!(
+            player.set_exclusion(now, now),
+            Some(now + PLAYER_LIMITS_COOLDOWN)
+        );
+        assert_eq!(
+            player.check_exclusion(now + PLAYER_LIMITS_COOLDOWN - 1),
+            Err(MyError::PlayerSelfExcluded.into())
+        );
+        assert!(player.check_exclusion(now + PLAYER_LIMITS_COOLDOWN).is_ok());
+        assert!(player.pending_exclusion.is_none());
+    }
+}
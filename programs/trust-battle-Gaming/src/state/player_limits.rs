@@ -0,0 +1,214 @@
+use anchor_lang::prelude::*;
+
+use crate::{errors::MyError, PLAYER_LIMITS_COOLDOWN, PLAYER_WAGER_BUCKET};
+
+/// Raised limits, only applied once the cooldown is over
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, InitSpace)]
+pub struct PendingPlayerLimits {
+    pub max_stake: Option<u64>,
+    pub max_daily_wager: Option<u64>,
+    pub effective_at: i64,
+}
+
+/// Self-imposed limits of a player for a single mint, `None` means no limit.
+///
+/// Created empty (no limits) the first time the player wagers with the mint.
+#[account]
+#[derive(InitSpace)]
+pub struct PlayerLimits {
+    pub max_stake: Option<u64>,
+    pub max_daily_wager: Option<u64>,
+    pub pending: Option<PendingPlayerLimits>,
+    // Wagered amount of each hour of the rolling 24 hours window, by hour % 24
+    pub hourly_wagered: [u64; 24],
+    // Hour of the latest wager, buckets of older hours are cleared on the next one
+    pub last_wager_hour: i64,
+    pub bump: u8,
+}
+
+impl PlayerLimits {
+    /// Lower limits apply right away, raised ones wait for the cooldown
+    pub fn set_limits(
+        &mut self,
+        max_stake: Option<u64>,
+        max_daily_wager: Option<u64>,
+        now: i64,
+    ) -> Option<i64> {
+        self.apply_pending(now);
+
+        let is_raise =
+            is_raise(max_stake, self.max_stake) || is_raise(max_daily_wager, self.max_daily_wager);
+
+        self.max_stake = lowest(max_stake, self.max_stake);
+        self.max_daily_wager = lowest(max_daily_wager, self.max_daily_wager);
+
+        // A new request replaces any raise that is still pending
+        self.pending = is_raise.then_some(PendingPlayerLimits {
+            max_stake,
+            max_daily_wager,
+            effective_at: now + PLAYER_LIMITS_COOLDOWN,
+        });
+        self.pending.map(|pending| pending.effective_at)
+    }
+
+    /// Check the stake against the limits and add it to the wagered amount
+    pub fn add_wager(&mut self, amount: u64, now: i64) -> Result<()> {
+        self.apply_pending(now);
+
+        if let Some(max_stake) = self.max_stake {
+            require!(amount <= max_stake, MyError::PlayerStakeLimit);
+        }
+
+        let hour = now.div_euclid(PLAYER_WAGER_BUCKET);
+        self.roll_window(hour);
+
+        if let Some(max_daily_wager) = self.max_daily_wager {
+            require!(
+                self.wagered()
+                    .checked_add(amount)
+                    .is_some_and(|wagered| wagered <= max_daily_wager),
+                MyError::PlayerDailyWagerLimit
+            );
+        }
+
+        let buckets = self.hourly_wagered.len() as i64;
+        let bucket = &mut self.hourly_wagered[hour.rem_euclid(buckets) as usize];
+        *bucket = bucket
+            .checked_add(amount)
+            .expect("Add wagered amount overflow");
+        Ok(())
+    }
+
+    /// Amount wagered over the last 24 hours, up to the latest wager
+    pub fn wagered(&self) -> u64 {
+        self.hourly_wagered
+            .iter()
+            .try_fold(0u64, |wagered, amount| wagered.checked_add(*amount))
+            .expect("Sum wagered amount overflow")
+    }
+
+    /// Clear the buckets of the hours that left the window since the latest wager
+    fn roll_window(&mut self, hour: i64) {
+        let buckets = self.hourly_wagered.len() as i64;
+        let expired = (hour - self.last_wager_hour).clamp(0, buckets);
+        for expired_hour in hour - expired + 1..=hour {
+            self.hourly_wagered[expired_hour.rem_euclid(buckets) as usize] = 0;
+        }
+        self.last_wager_hour = self.last_wager_hour.max(hour);
+    }
+
+    fn apply_pending(&mut self, now: i64) {
+        if let Some(pending) = self.pending.filter(|pending| now >= pending.effective_at) {
+            self.max_stake = pending.max_stake;
+            self.max_daily_wager = pending.max_daily_wager;
+            self.pending = None;
+        }
+    }
+}
+
+/// `None` is no limit, so it is higher than any amount
+fn is_raise(new: Option<u64>, current: Option<u64>) -> bool {
+    match (new, current) {
+        (_, None) => false,
+        (None, Some(_)) => true,
+        (Some(new), Some(current)) => new > current,
+    }
+}
+
+fn lowest(new: Option<u64>, current: Option<u64>) -> Option<u64> {
+    match (new, current) {
+        (Some(new), Some(current)) => Some(new.min(current)),
+        (new, current) => new.or(current),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: i64 = PLAYER_WAGER_BUCKET;
+    const NOW: i64 = 1_700_000_000;
+
+    fn limits(max_stake: Option<u64>, max_daily_wager: Option<u64>) -> PlayerLimits {
+        PlayerLimits {
+            max_stake,
+            max_daily_wager,
+            pending: None,
+            hourly_wagered: [0; 24],
+            last_wager_hour: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn none_is_higher_than_any_limit() {
+        assert!(is_raise(None, Some(10)));
+        assert!(is_raise(Some(11), Some(10)));
+        assert!(!is_raise(Some(10), Some(10)));
+        assert!(!is_raise(Some(9), Some(10)));
+        assert!(!is_raise(Some(10), None));
+        assert!(!is_raise(None, None));
+
+        assert_eq!(lowest(Some(9), Some(10)), Some(9));
+        assert_eq!(lowest(Some(11), Some(10)), Some(10));
+        assert_eq!(lowest(None, Some(10)), Some(10));
+        assert_eq!(lowest(Some(10), None), Some(10));
+        assert_eq!(lowest(None, None), None);
+    }
+
+    #[test]
+    fn lower_limits_apply_right_away() {
+        let mut limits = limits(None, None);
+        assert_eq!(limits.set_limits(Some(10), Some(100), NOW), None);
+        assert_eq!(limits.max_stake, Some(10));
+        assert_eq!(limits.max_daily_wager, Some(100));
+        assert!(limits.pending.is_none());
+    }
+
+    #[test]
+    fn raised_limits_wait_for_the_cooldown() {
+        let mut limits = limits(Some(10), Some(100));
+        assert_eq!(
+            limits.set_limits(None, Some(50), NOW),
+            Some(NOW + PLAYER_LIMITS_COOLDOWN)
+        );
+        // The lowered daily limit applies now, the removed stake limit later
+        assert_eq!(limits.max_stake, Some(10));
+        assert_eq!(limits.max_daily_wager, Some(50));
+
+        assert_eq!(
+            limits.add_wager(11, NOW + PLAYER_LIMITS_COOLDOWN - 1),
+            Err(MyError::PlayerStakeLimit.into())
+        );
+        assert!(limits.add_wager(11, NOW + PLAYER_LIMITS_COOLDOWN).is_ok());
+        assert_eq!(limits.max_stake, None);
+        assert!(limits.pending.is_none());
+    }
+
+    #[test]
+    fn daily_wager_limit_counts_the_last_24_hours() {
+        let mut limits = limits(None, Some(100));
+        assert!(limits.add_wager(60, NOW).is_ok());
+        assert!(limits.add_wager(40, NOW + 12 * HOUR).is_ok());
+        assert_eq!(
+            limits.add_wager(1, NOW + 23 * HOUR),
+            Err(MyError::PlayerDailyWagerLimit.into())
+        );
+
+        // First wager left the window, the second one is still in it
+        assert!(limits.add_wager(60, NOW + 24 * HOUR).is_ok());
+        assert_eq!(limits.wagered(), 100);
+        assert_eq!(
+            limits.add_wager(1, NOW + 24 * HOUR),
+            Err(MyError::PlayerDailyWagerLimit.into())
+        );
+    }
+
+    #[test]
+    fn idle_window_is_cleared() {
+        let mut limits = limits(None, Some(100));
+        assert!(limits.add_wager(100, NOW).is_ok());
+        assert!(limits.add_wager(100, NOW + 30 * 24 * HOUR).is_ok());
+        assert_eq!(limits.wagered(), 100);
+    }
+}
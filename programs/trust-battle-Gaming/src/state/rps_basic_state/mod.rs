@@ -1,25 +1,62 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MyError;
+
 pub mod rps_basic_game;
 pub use rps_basic_game::*;
 
 pub mod rps_basic_player;
 pub use rps_basic_player::*;
 
+pub mod rps_basic_result;
+pub use rps_basic_result::*;
+
+/// RPS choice, serialized as
+/// ```text
+/// 0 = Rock
+/// 1 = Paper
+/// 2 = Scissors
+/// ```
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
+pub enum RpsChoice {
+    Rock,
+    Paper,
+    Scissors,
+}
+
+impl TryFrom<u8> for RpsChoice {
+    type Error = Error;
+
+    fn try_from(value: u8) -> Result<Self> {
+        match value {
+            0 => Ok(RpsChoice::Rock),
+            1 => Ok(RpsChoice::Paper),
+            2 => Ok(RpsChoice::Scissors),
+            _ => err!(MyError::RpsBasicInvalidChoice),
+        }
+    }
+}
+
+impl From<RpsChoice> for u8 {
+    fn from(value: RpsChoice) -> Self {
+        value as u8
+    }
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace)]
 pub enum GameResult {
     Player1,
     Player2,
     Draw,
 }
 
-/// Calculate the result of 2 RPS choices where
-/// ```
-/// 0 = Rock
-/// 1 = Paper
-/// 2 = Scissors
-/// ```
-pub fn calculate_result(choice_1: u8, choice_2: u8) -> GameResult {
+/// Calculate the result of 2 RPS choices
+pub fn calculate_result(choice_1: RpsChoice, choice_2: RpsChoice) -> GameResult {
+    use RpsChoice::*;
+
     match (choice_1, choice_2) {
-        (1, 0) | (2, 1) | (0, 2) => GameResult::Player1,
-        (0, 1) | (1, 2) | (2, 0) => GameResult::Player2,
+        (Paper, Rock) | (Scissors, Paper) | (Rock, Scissors) => GameResult::Player1,
+        (Rock, Paper) | (Paper, Scissors) | (Scissors, Rock) => GameResult::Player2,
         _ => GameResult::Draw,
     }
 }
@@ -45,3 +82,147 @@ pub fn calculate_fee(amount: u64, client_bps: u16, platform_bps: u16) -> (u64, u
 
     (final_amount, client_amount, platform_amount)
 }
+
+/// Split the client fee between the client that created the game and the client
+/// player2 joined from, returns `(origin_amount, join_amount)`
+pub fn split_client_fee(client_amount: u64, join_client_share_bps: u16) -> (u64, u64) {
+    let join_amount = client_amount
+        .checked_mul(join_client_share_bps as u64)
+        .expect("Join client fee mul overflow")
+        .checked_div(10000)
+        .unwrap();
+
+    let origin_amount = client_amount
+        .checked_sub(join_amount)
+        .expect("Origin client fee sub overflow");
+
+    (origin_amount, join_amount)
+}
+
+/// Scale of `RpsBasicPlayer.rating`, ratings are fixed-point with 2 decimals
+pub const RPS_BASIC_RATING_SCALE: u32 = 100;
+
+/// Rating of a new player (1200.00)
+pub const RPS_BASIC_DEFAULT_RATING: u32 = 1200 * RPS_BASIC_RATING_SCALE;
+
+/// K-factor, the most rating points a single game can move
+pub const RPS_BASIC_RATING_K: u32 = 32;
+
+/// Rating difference between 2 entries of `EXPECTED_SCORE_BPS`
+const EXPECTED_SCORE_STEP: u32 = 50 * RPS_BASIC_RATING_SCALE;
+
+/// Expected score (BPS) of the higher rated player, `1 / (1 + 10^(-diff / 400))`
+/// for every `EXPECTED_SCORE_STEP` of rating difference
+const EXPECTED_SCORE_BPS: [u32; 17] = [
+    5000, 5715, 6401, 7034, 7597, 8083, 8490, 8823, 9091, 9302, 9468, 9595, 9693, 9768, 9825, 9868,
+    9901,
+];
+
+/// Expected score in BPS of a player against an opponent, linearly interpolated
+/// between the entries of the lookup table
+pub fn expected_score(rating: u32, opponent_rating: u32) -> u32 {
+    let diff = rating.abs_diff(opponent_rating);
+    let index = (diff / EXPECTED_SCORE_STEP) as usize;
+
+    let higher_score = match EXPECTED_SCORE_BPS.get(index + 1) {
+        Some(next) => {
+            let current = EXPECTED_SCORE_BPS[index];
+            current + (next - current) * (diff % EXPECTED_SCORE_STEP) / EXPECTED_SCORE_STEP
+        }
+        // Difference is past the table, the higher rated player is expected to win
+        None => EXPECTED_SCORE_BPS[EXPECTED_SCORE_BPS.len() - 1],
+    };
+
+    if rating >= opponent_rating {
+        higher_score
+    } else {
+        10000 - higher_score
+    }
+}
+
+/// Calculate the new Elo ratings of both players after a game,
+/// returns `(player1_rating, player2_rating)`
+pub fn calculate_rating(
+    player1_rating: u32,
+    player2_rating: u32,
+    result: GameResult,
+) -> (u32, u32) {
+    let player1_score: i64 = match result {
+        GameResult::Player1 => 10000,
+        GameResult::Player2 => 0,
+        GameResult::Draw => 5000,
+    };
+    let player1_expected = expected_score(player1_rating, player2_rating) as i64;
+
+    // Player2 moves by the same amount in the other direction
+    let delta = (RPS_BASIC_RATING_K * RPS_BASIC_RATING_SCALE) as i64
+        * (player1_score - player1_expected)
+        / 10000;
+
+    (
+        apply_rating_delta(player1_rating, delta),
+        apply_rating_delta(player2_rating, -delta),
+    )
+}
+
+fn apply_rating_delta(rating: u32, delta: i64) -> u32 {
+    (rating as i64 + delta).clamp(0, u32::MAX as i64) as u32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const R: u32 = RPS_BASIC_DEFAULT_RATING;
+
+    #[test]
+    fn expected_score_of_equal_ratings() {
+        assert_eq!(expected_score(R, R), 5000);
+    }
+
+    #[test]
+    fn expected_score_table_boundaries() {
+        // Exactly on the table entries
+        assert_eq!(expected_score(R + EXPECTED_SCORE_STEP, R), 5715);
+        assert_eq!(expected_score(R, R + EXPECTED_SCORE_STEP), 10000 - 5715);
+        assert_eq!(expected_score(R + 16 * EXPECTED_SCORE_STEP, R), 9901);
+
+        // Half way between 2 entries
+        assert_eq!(expected_score(R + EXPECTED_SCORE_STEP / 2, R), 5357);
+
+        // Just before the last entry, and past the end of the table
+        assert_eq!(expected_score(R + 16 * EXPECTED_SCORE_STEP - 1, R), 9900);
+        assert_eq!(expected_score(R + 20 * EXPECTED_SCORE_STEP, R), 9901);
+        assert_eq!(expected_score(R, R + 20 * EXPECTED_SCORE_STEP), 99);
+    }
+
+    #[test]
+    fn rating_changes_on_win_lose_and_draw() {
+        assert_eq!(
+            calculate_rating(R, R, GameResult::Player1),
+            (R + 1600, R - 1600)
+        );
+        assert_eq!(
+            calculate_rating(R, R, GameResult::Player2),
+            (R - 1600, R + 1600)
+        );
+        assert_eq!(calculate_rating(R, R, GameResult::Draw), (R, R));
+
+        // Upset win moves more points than an expected win
+        let (_, favorite) =
+            calculate_rating(R, R + 200 * RPS_BASIC_RATING_SCALE, GameResult::Player1);
+        assert_eq!(favorite, R + 200 * RPS_BASIC_RATING_SCALE - 2431);
+
+        // Draw against a stronger player still gains points
+        let (underdog, _) = calculate_rating(R, R + 200 * RPS_BASIC_RATING_SCALE, GameResult::Draw);
+        assert_eq!(underdog, R + 831);
+    }
+
+    #[test]
+    fn rating_never_goes_below_zero() {
+        assert_eq!(
+            calculate_rating(1000, 100, GameResult::Player1),
+            (1000 + 1559, 0)
+        );
+    }
+}
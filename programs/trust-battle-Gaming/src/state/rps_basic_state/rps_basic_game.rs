@@ -1,4 +1,17 @@
-use anchor_lang::prelude::*;
+use anchor_lang::{
+    prelude::*,
+    solana_program::hash::{hash, hashv},
+};
+
+use crate::{
+    calculate_fee, calculate_result,
+    errors::MyError,
+    events::{RpsBasicGameJoined, RpsBasicRematchStarted},
+    split_client_fee, GameResult, RpsChoice, VerifierConfig, DEFAULT_RPS_BASIC_TIMEOUT, SOL_MINT,
+};
+
+// Longest series a game can be played as
+pub const RPS_BASIC_MAX_BEST_OF: u8 = 9;
 
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct Player1Info {
@@ -9,17 +22,573 @@ pub struct Player1Info {
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
 pub struct Player2Info {
     pub key: Pubkey,
-    pub choice: u8,
+    // Game client player2 joined from, gets a share of the client fee
+    pub game_client: Pubkey,
+    // None while waiting for player2 to pick again in a rematch
+    pub choice: Option<RpsChoice>,
+}
+
+/// Opponent player1 invited to a private game
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, InitSpace)]
+pub enum RpsBasicOpponent {
+    // Key of the opponent `Player` account
+    Player(Pubkey),
+    // sha256 of the opponent username
+    UsernameHash([u8; 32]),
+}
+
+impl RpsBasicOpponent {
+    pub fn is_invited(&self, player_key: &Pubkey, username: &str) -> bool {
+        match self {
+            RpsBasicOpponent::Player(key) => key == player_key,
+            RpsBasicOpponent::UsernameHash(username_hash) => {
+                hash(username.as_bytes()).to_bytes() == *username_hash
+            }
+        }
+    }
+}
+
+/// How player1 reveals his choice to complete a round
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum RevealMode {
+    // ZK proof of the choice hash, verified with the game verifier config
+    Zk,
+    // Choice and salt are revealed and hashed on chain, cheaper but the salt becomes public
+    Hash,
 }
 
 #[account]
 #[derive(InitSpace)]
 pub struct RpsBasicGame {
     pub game_client: Pubkey,
+    // Token the game is played with, stakes are held in the vault of this mint
+    pub mint: Pubkey,
+    pub reveal_mode: RevealMode,
+    // Verifier config the game was created with, only set for `RevealMode::Zk`
+    pub verifier_config: Option<Pubkey>,
     pub player1: Player1Info,
     pub player2: Option<Player2Info>,
+    // Only this opponent can join, anyone can join when not set
+    pub opponent: Option<RpsBasicOpponent>,
     pub id: u64,
     pub amount: u64,
+    // Amount the vault actually received from each player, lower than `amount`
+    // for mints with a transfer fee
+    pub player1_deposit: u64,
+    pub player2_deposit: u64,
+    // Fee BPS at the time the game was created, used to settle the game
+    pub client_fee: u16,
+    pub platform_fee: u16,
+    // BPS of the client fee that goes to the joining client
+    pub join_client_share: u16,
     pub timeout: Option<i64>,
+    // How many more times a draw can be replayed before the pot is split
+    pub rematches_left: u8,
+    // Current round, increased on every rematch and series round
+    pub round: u8,
+    // Number of rounds in the series, 1 for a single game
+    pub best_of: u8,
+    // Rounds won by each player in the series
+    pub player1_wins: u8,
+    pub player2_wins: u8,
+    // Drawn rounds with no rematch left, played without a winner
+    pub drawn_rounds: u8,
     pub bump: u8,
 }
+
+/// Amounts paid out of the vault when a game is settled
+pub struct RpsBasicPayout {
+    pub player1_amount: u64,
+    pub player2_amount: u64,
+    pub origin_client_amount: u64,
+    pub join_client_amount: u64,
+    pub platform_amount: u64,
+}
+
+impl RpsBasicGame {
+    /// SOL games hold their stakes as lamports in the `SolVault`
+    pub fn is_sol(&self) -> bool {
+        self.mint == SOL_MINT
+    }
+
+    /// Game id player1 uses for his choice hash in the current round.
+    ///
+    /// Every rematch round gets its own id, so picking the same choice again
+    /// doesn't produce the same (already revealed) hash.
+    pub fn round_id(&self) -> u64 {
+        self.id | ((self.round as u64) << 56)
+    }
+
+    /// SOL fees are paid into plain system accounts, which can't be left with
+    /// less than the rent-exempt minimum. Every fee share this game can pay,
+    /// on the full pot or on a cancellation, must cover it on its own.
+    pub fn validate_sol_fees(&self, rent_minimum: u64) -> Result<()> {
+        // SOL has no transfer fee, player2 stakes the same amount
+        let pot = self.amount.checked_mul(2).expect("Full pot mul overflow");
+        let (_, client_amount, platform_amount) =
+            calculate_fee(pot, self.client_fee, self.platform_fee);
+        let (origin_client_amount, join_client_amount) =
+            split_client_fee(client_amount, self.join_client_share);
+        let (_, cancel_client_amount, _) = calculate_fee(self.player1_deposit, self.client_fee, 0);
+
+        require!(
+            [
+                platform_amount,
+                origin_client_amount,
+                join_client_amount,
+                cancel_client_amount
+            ]
+            .iter()
+            .all(|&amount| amount == 0 || amount >= rent_minimum),
+            MyError::SolStakeBelowRent
+        );
+        Ok(())
+    }
+
+    /// Total amount held in the vault for this game
+    pub fn pot(&self) -> u64 {
+        self.player1_deposit
+            .checked_add(self.player2_deposit)
+            .expect("Add game pot overflow")
+    }
+
+    /// Player2 joins the game once his stake is in the vault
+    pub fn join(
+        &mut self,
+        player2: Pubkey,
+        join_client: Pubkey,
+        player2_choice: RpsChoice,
+        player2_deposit: u64,
+    ) -> Result<()> {
+        self.player2_deposit = player2_deposit;
+        self.player2 = Some(Player2Info {
+            key: player2,
+            game_client: join_client,
+            choice: Some(player2_choice),
+        });
+
+        self.timeout = Some(Clock::get()?.unix_timestamp + DEFAULT_RPS_BASIC_TIMEOUT);
+
+        emit!(RpsBasicGameJoined {
+            game_client: self.game_client,
+            id: self.id,
+            player2,
+            join_client,
+            player2_choice,
+        });
+        Ok(())
+    }
+
+    /// Verify player1 revealed his choice of the current round, with the game reveal mode
+    pub fn verify_reveal(
+        &self,
+        verifier_config: Option<&VerifierConfig>,
+        proof: &[u8],
+        salt: Option<[u8; 32]>,
+        player1_choice: RpsChoice,
+    ) -> Result<()> {
+        match self.reveal_mode {
+            RevealMode::Zk => self.verify_reveal_proof(
+                verifier_config.ok_or(MyError::RpsBasicVerifierRequired)?,
+                proof,
+                player1_choice,
+            ),
+            RevealMode::Hash => {
+                self.verify_reveal_hash(salt.ok_or(MyError::RpsBasicSaltRequired)?, player1_choice)
+            }
+        }
+    }
+
+    fn verify_reveal_proof(
+        &self,
+        verifier_config: &VerifierConfig,
+        proof: &[u8],
+        player1_choice: RpsChoice,
+    ) -> Result<()> {
+        // Get public input for verification
+        let public_inputs: Vec<u8> = zk_games_types::RpsBasicPublic {
+            client_pubkey: self.game_client.to_string(),
+            game_id: self.round_id(),
+            choice_hash: self.player1.choice_hash,
+            choice: player1_choice.into(),
+        }
+        .into();
+
+        verifier_config
+            .verify_proof(proof, &public_inputs)
+            .map_err(|x| {
+                msg!("{:?}", x);
+                MyError::RpsBasicProofVerify
+            })?;
+        Ok(())
+    }
+
+    /// Choice hash is `sha256(choice || salt || game_id || client)`
+    fn verify_reveal_hash(&self, salt: [u8; 32], player1_choice: RpsChoice) -> Result<()> {
+        let choice_hash = hashv(&[
+            &[player1_choice.into()],
+            &salt,
+            &self.round_id().to_le_bytes(),
+            self.game_client.as_ref(),
+        ]);
+
+        require!(
+            choice_hash.to_bytes() == self.player1.choice_hash,
+            MyError::RpsBasicHashMismatch
+        );
+        Ok(())
+    }
+
+    /// Play the revealed round against player2 choice.
+    ///
+    /// Returns the game result once the game is decided, otherwise the draw is
+    /// replayed or the series moves to the next round with `next_choice_hash`.
+    pub fn play_round(
+        &mut self,
+        player1_choice: RpsChoice,
+        player2_choice: RpsChoice,
+        next_choice_hash: Option<[u8; 32]>,
+        now: i64,
+    ) -> Result<Option<GameResult>> {
+        let round_result = calculate_result(player1_choice, player2_choice);
+        // A draw is replayed while rematches are left, otherwise it is a played round
+        let is_rematch = matches!(round_result, GameResult::Draw) && self.rematches_left > 0;
+
+        // Stake stays in the vault until the game is decided
+        if !is_rematch && self.add_round_result(round_result) {
+            return Ok(Some(self.series_result()));
+        }
+
+        let next_choice_hash = next_choice_hash.ok_or(MyError::RpsBasicRematchChoiceHashMissing)?;
+
+        if is_rematch {
+            self.rematches_left -= 1;
+        }
+        self.round += 1;
+        self.player1.choice_hash = next_choice_hash;
+        if let Some(player2) = self.player2.as_mut() {
+            player2.choice = None;
+        }
+        // Player2 must pick again before the timeout
+        self.timeout = Some(now + DEFAULT_RPS_BASIC_TIMEOUT);
+
+        emit!(RpsBasicRematchStarted {
+            game_client: self.game_client,
+            id: self.id,
+            round: self.round,
+            player1_choice,
+            player2_choice,
+            result: round_result,
+            player1_wins: self.player1_wins,
+            player2_wins: self.player2_wins,
+        });
+
+        Ok(None)
+    }
+
+    /// Result of a game claimed after the timeout, the player we were waiting on forfeits
+    pub fn timeout_result(&self) -> Result<GameResult> {
+        // The player we are waiting on had until the timeout to act
+        let timeout = self.timeout.ok_or(MyError::RpsBasicGameNotJoined)?;
+        require!(
+            Clock::get()?.unix_timestamp > timeout,
+            MyError::RpsBasicTimeoutNotReached
+        );
+
+        let player2 = self
+            .player2
+            .as_ref()
+            .expect("Player2 must be set to claim timeout");
+
+        Ok(match player2.choice {
+            // Player1 failed to reveal, so player2 wins the game (or series) by forfeit
+            Some(_) => GameResult::Player2,
+            // Player2 failed to pick for the next round, so player1 wins by forfeit
+            None => GameResult::Player1,
+        })
+    }
+
+    /// Split the pot for the game result, fees are only taken once on the whole pot
+    pub fn payout(&self, result: GameResult) -> RpsBasicPayout {
+        let (winning_amount, client_amount, platform_amount) =
+            calculate_fee(self.pot(), self.client_fee, self.platform_fee);
+        let (origin_client_amount, join_client_amount) =
+            split_client_fee(client_amount, self.join_client_share);
+
+        let (player1_amount, player2_amount) = match result {
+            GameResult::Player1 => (winning_amount, 0),
+            GameResult::Player2 => (0, winning_amount),
+            GameResult::Draw => {
+                // No rematch left, split winning
+                let split_amount = winning_amount
+                    .checked_div(2)
+                    .expect("Split winning amount zero");
+                (split_amount, split_amount)
+            }
+        };
+
+        RpsBasicPayout {
+            player1_amount,
+            player2_amount,
+            origin_client_amount,
+            join_client_amount,
+            platform_amount,
+        }
+    }
+
+    /// Series must have an odd number of rounds, and every round (including
+    /// rematches) must fit in the `round` counter
+    pub fn validate_series(best_of: u8, max_rematches: u8) -> Result<()> {
+        require!(
+            best_of % 2 == 1
+                && best_of <= RPS_BASIC_MAX_BEST_OF
+                && best_of as u16 + max_rematches as u16 <= u8::MAX as u16 + 1,
+            MyError::RpsBasicInvalidBestOf
+        );
+        Ok(())
+    }
+
+    /// Record a played round, returns true once a player won the majority of
+    /// the rounds or all rounds of the series were played
+    pub fn add_round_result(&mut self, result: GameResult) -> bool {
+        let wins_needed = self.best_of / 2 + 1;
+        match result {
+            GameResult::Player1 => self.player1_wins += 1,
+            GameResult::Player2 => self.player2_wins += 1,
+            GameResult::Draw => self.drawn_rounds += 1,
+        }
+        self.player1_wins >= wins_needed
+            || self.player2_wins >= wins_needed
+            || self.player1_wins + self.player2_wins + self.drawn_rounds >= self.best_of
+    }
+
+    /// Result of the series from the rounds won so far
+    pub fn series_result(&self) -> GameResult {
+        match self.player1_wins.cmp(&self.player2_wins) {
+            std::cmp::Ordering::Greater => GameResult::Player1,
+            std::cmp::Ordering::Less => GameResult::Player2,
+            std::cmp::Ordering::Equal => GameResult::Draw,
+        }
+    }
+
+    /// Player2 of a joined game, the default key before anyone joined
+    pub fn player2_key(&self) -> Pubkey {
+        self.player2
+            .as_ref()
+            .map_or(Pubkey::default(), |player2| player2.key)
+    }
+
+    /// Game client player2 joined from, the default key before anyone joined
+    pub fn join_client(&self) -> Pubkey {
+        self.player2
+            .as_ref()
+            .map_or(Pubkey::default(), |player2| player2.game_client)
+    }
+
+    /// Game client the game was created on or player2 joined from
+    pub fn is_game_client(&self, game_client: &Pubkey) -> bool {
+        self.game_client == *game_client
+            || self
+                .player2
+                .as_ref()
+                .is_some_and(|player2| player2.game_client == *game_client)
+    }
+
+    /// Public games can be joined by anyone, private ones only by the invited opponent
+    pub fn can_join(&self, player_key: &Pubkey, username: &str) -> bool {
+        match &self.opponent {
+            Some(opponent) => opponent.is_invited(player_key, username),
+            None => true,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn series(best_of: u8, rematches_left: u8) -> RpsBasicGame {
+        RpsBasicGame {
+            game_client: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            reveal_mode: RevealMode::Hash,
+            verifier_config: None,
+            player1: Player1Info {
+                key: Pubkey::new_unique(),
+                choice_hash: [0; 32],
+            },
+            player2: None,
+            opponent: None,
+            id: 0,
+            amount: 1_000_000,
+            player1_deposit: 1_000_000,
+            player2_deposit: 1_000_000,
+            client_fee: 50,
+            platform_fee: 50,
+            join_client_share: 5000,
+            timeout: None,
+            rematches_left,
+            round: 0,
+            best_of,
+            player1_wins: 0,
+            player2_wins: 0,
+            drawn_rounds: 0,
+            bump: 255,
+        }
+    }
+
+    #[test]
+    fn best_of_3_decided_2_0() {
+        let mut game = series(3, 0);
+        assert!(!game.add_round_result(GameResult::Player1));
+        assert!(game.add_round_result(GameResult::Player1));
+        assert!(matches!(game.series_result(), GameResult::Player1));
+        assert_eq!((game.player1_wins, game.player2_wins), (2, 0));
+    }
+
+    #[test]
+    fn best_of_3_decided_2_1() {
+        let mut game = series(3, 0);
+        assert!(!game.add_round_result(GameResult::Player1));
+        assert!(!game.add_round_result(GameResult::Player2));
+        assert!(game.add_round_result(GameResult::Player2));
+        assert!(matches!(game.series_result(), GameResult::Player2));
+        assert_eq!((game.player1_wins, game.player2_wins), (1, 2));
+    }
+
+    #[test]
+    fn drawn_rounds_are_played_without_a_winner() {
+        let mut game = series(3, 0);
+        assert!(!game.add_round_result(GameResult::Player1));
+        assert!(!game.add_round_result(GameResult::Draw));
+        assert!(game.add_round_result(GameResult::Player2));
+        assert!(matches!(game.series_result(), GameResult::Draw));
+        assert_eq!(
+            (game.player1_wins, game.player2_wins, game.drawn_rounds),
+            (1, 1, 1)
+        );
+    }
+
+    #[test]
+    fn draw_after_a_lead_doesnt_decide_the_series() {
+        let mut game = series(3, 0);
+        game.player1_wins = 1;
+
+        // 1-0 then a draw, one round is still to be played
+        assert!(matches!(
+            game.play_round(RpsChoice::Rock, RpsChoice::Rock, Some([1; 32]), 0),
+            Ok(None)
+        ));
+        assert_eq!((game.round, game.drawn_rounds), (1, 1));
+
+        assert!(matches!(
+            game.play_round(RpsChoice::Paper, RpsChoice::Rock, None, 0),
+            Ok(Some(GameResult::Player1))
+        ));
+    }
+
+    #[test]
+    fn draw_with_rematch_left_is_replayed() {
+        let mut game = series(3, 1);
+        game.player1_wins = 1;
+
+        assert!(matches!(
+            game.play_round(RpsChoice::Rock, RpsChoice::Rock, Some([1; 32]), 0),
+            Ok(None)
+        ));
+        assert_eq!((game.rematches_left, game.drawn_rounds), (0, 0));
+    }
+
+    #[test]
+    fn last_drawn_round_decides_the_series() {
+        let mut game = series(3, 0);
+        game.player1_wins = 1;
+        game.player2_wins = 1;
+
+        // Series is even, so the pot is split
+        assert!(matches!(
+            game.play_round(RpsChoice::Rock, RpsChoice::Rock, None, 0),
+            Ok(Some(GameResult::Draw))
+        ));
+    }
+
+    #[test]
+    fn single_game_draw_without_rematch_is_split() {
+        let mut game = series(1, 0);
+        assert!(matches!(
+            game.play_round(RpsChoice::Rock, RpsChoice::Rock, None, 0),
+            Ok(Some(GameResult::Draw))
+        ));
+    }
+
+    #[test]
+    fn winning_round_decides_the_series() {
+        let mut game = series(3, 1);
+        game.player1_wins = 1;
+
+        assert!(matches!(
+            game.play_round(RpsChoice::Paper, RpsChoice::Rock, None, 0),
+            Ok(Some(GameResult::Player1))
+        ));
+        assert_eq!(game.rematches_left, 1);
+    }
+
+    #[test]
+    fn series_length_must_be_odd_and_fit_the_round_counter() {
+        assert!(RpsBasicGame::validate_series(1, 0).is_ok());
+        assert!(RpsBasicGame::validate_series(3, 2).is_ok());
+        assert!(RpsBasicGame::validate_series(RPS_BASIC_MAX_BEST_OF, 247).is_ok());
+
+        assert!(RpsBasicGame::validate_series(0, 0).is_err());
+        assert!(RpsBasicGame::validate_series(2, 0).is_err());
+        assert!(RpsBasicGame::validate_series(RPS_BASIC_MAX_BEST_OF + 2, 0).is_err());
+        assert!(RpsBasicGame::validate_series(RPS_BASIC_MAX_BEST_OF, 248).is_err());
+    }
+
+    const RENT_MINIMUM: u64 = 890_880;
+
+    fn sol_game(amount: u64, client_fee: u16, platform_fee: u16) -> RpsBasicGame {
+        RpsBasicGame {
+            mint: SOL_MINT,
+            amount,
+            player1_deposit: amount,
+            player2_deposit: 0,
+            client_fee,
+            platform_fee,
+            ..series(1, 0)
+        }
+    }
+
+    #[test]
+    fn sol_fee_shares_must_cover_rent() {
+        // 0.25% of a 2 SOL pot to each client, 0.5% to the platform
+        assert!(sol_game(1_000_000_000, 50, 50)
+            .validate_sol_fees(RENT_MINIMUM)
+            .is_ok());
+        assert_eq!(
+            sol_game(100_000_000, 50, 50).validate_sol_fees(RENT_MINIMUM),
+            Err(MyError::SolStakeBelowRent.into())
+        );
+    }
+
+    #[test]
+    fn sol_cancel_fee_must_cover_rent() {
+        let sol_game = |amount| RpsBasicGame {
+            join_client_share: 0,
+            ..sol_game(amount, 100, 0)
+        };
+        // 1% of a 0.1 SOL pot all goes to the origin client, cancelling takes half of it
+        assert!(sol_game(100_000_000)
+            .validate_sol_fees(RENT_MINIMUM)
+            .is_ok());
+        assert_eq!(
+            sol_game(50_000_000).validate_sol_fees(RENT_MINIMUM),
+            Err(MyError::SolStakeBelowRent.into())
+        );
+    }
+
+    #[test]
+    fn zero_sol_fees_need_no_rent() {
+        assert!(sol_game(1, 0, 0).validate_sol_fees(RENT_MINIMUM).is_ok());
+    }
+}
@@ -1,38 +1,71 @@
 use anchor_lang::prelude::*;
 
+use crate::{calculate_rating, GameResult, RpsChoice};
+
 #[account]
 #[derive(Default, InitSpace)]
 pub struct RpsBasicPlayer {
+    // Games and series count as one game, whatever the number of rounds
     pub total_games: u64,
     pub total_draws: u64,
     pub total_wins: u64,
     pub total_losses: u64,
     pub total_cancel: u64,
+    // Rounds played, including rematches and every round of a series
+    pub total_rounds: u64,
     pub total_choices: [u64; 3],
+    // Elo rating, fixed-point with `RPS_BASIC_RATING_SCALE`
+    pub rating: u32,
     pub bump: u8,
 }
 
 impl RpsBasicPlayer {
-    fn add_game(&mut self, choice: u8) {
-        self.total_games += 1;
+    /// Record the choice of a revealed round
+    pub fn add_round(&mut self, choice: RpsChoice) {
+        self.total_rounds += 1;
         self.total_choices[choice as usize] += 1;
     }
 
-    pub fn add_win(&mut self, choice: u8) {
-        self.add_game(choice);
+    pub fn add_win(&mut self) {
+        self.total_games += 1;
         self.total_wins += 1;
     }
 
-    pub fn add_lose(&mut self, choice: u8) {
-        self.add_game(choice);
+    pub fn add_lose(&mut self) {
+        self.total_games += 1;
         self.total_losses += 1;
     }
 
-    pub fn add_draw(&mut self, choice: u8) {
-        self.add_game(choice);
+    pub fn add_draw(&mut self) {
+        self.total_games += 1;
         self.total_draws += 1;
     }
 
+    /// Record the game result of both players and update their rating
+    pub fn add_result(player1: &mut Self, player2: &mut Self, result: GameResult) {
+        match result {
+            GameResult::Player1 => {
+                player1.add_win();
+                player2.add_lose();
+            }
+            GameResult::Player2 => {
+                player1.add_lose();
+                player2.add_win();
+            }
+            GameResult::Draw => {
+                player1.add_draw();
+                player2.add_draw();
+            }
+        }
+
+        Self::update_rating(player1, player2, result);
+    }
+
+    /// Update the rating of both players with the game result
+    pub fn update_rating(player1: &mut Self, player2: &mut Self, result: GameResult) {
+        (player1.rating, player2.rating) = calculate_rating(player1.rating, player2.rating, result);
+    }
+
     pub fn add_cancel(&mut self) {
         self.total_cancel += 1;
     }
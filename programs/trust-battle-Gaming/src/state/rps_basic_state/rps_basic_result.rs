@@ -0,0 +1,24 @@
+use anchor_lang::prelude::*;
+
+use crate::{GameResult, RpsChoice};
+
+/// Receipt of a completed game, kept after the game account is closed
+#[account]
+#[derive(InitSpace)]
+pub struct RpsBasicResult {
+    pub game_client: Pubkey,
+    pub id: u64,
+    pub player1: Pubkey,
+    pub player2: Pubkey,
+    // Stake of each player
+    pub amount: u64,
+    // Choices of the last round
+    pub player1_choice: RpsChoice,
+    pub player2_choice: RpsChoice,
+    pub best_of: u8,
+    pub player1_wins: u8,
+    pub player2_wins: u8,
+    pub result: GameResult,
+    pub timestamp: i64,
+    pub bump: u8,
+}
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MyError;
+
+/// Program owned vault holding the stakes of SOL games
+#[account]
+#[derive(InitSpace)]
+pub struct SolVault {
+    pub bump: u8,
+}
+
+/// Move lamports out of a program owned account (vault or player), it must stay rent exempt
+pub fn transfer_lamports(from: &AccountInfo, to: &AccountInfo, amount: u64) -> Result<()> {
+    let min_balance = Rent::get()?.minimum_balance(from.data_len());
+    require!(
+        from.lamports()
+            .checked_sub(amount)
+            .is_some_and(|left| left >= min_balance),
+        MyError::InsufficientLamports
+    );
+
+    **from.try_borrow_mut_lamports()? -= amount;
+    **to.try_borrow_mut_lamports()? += amount;
+    Ok(())
+}
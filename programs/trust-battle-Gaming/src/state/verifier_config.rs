@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+
+use crate::errors::MyError;
+
+// "0x" followed by the 32 bytes hash in hex
+pub const VKEY_HASH_LEN: usize = 66;
+// Serialized Groth16 verifying key, as shipped by sp1-solana for each SP1 version
+pub const GROTH16_VK_MAX_LEN: usize = 1024;
+
+/// Circuits that are verified with a ZK proof
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Eq, InitSpace, Debug)]
+pub enum CircuitType {
+    RpsBasic,
+    PlayerLogin,
+    PlayerWithdraw,
+}
+
+/// Verifying key of a circuit, registered by the admin.
+///
+/// A config is never changed once registered, a new circuit is registered
+/// as a new version so running games keep verifying with their own key.
+/// Player proofs can use any enabled version of their circuit.
+#[account]
+#[derive(InitSpace)]
+pub struct VerifierConfig {
+    pub circuit_type: CircuitType,
+    pub version: u16,
+    // SP1 program vkey hash
+    #[max_len(VKEY_HASH_LEN)]
+    pub vkey_hash: String,
+    // Groth16 verifying key of the SP1 version the circuit was built with
+    #[max_len(GROTH16_VK_MAX_LEN)]
+    pub groth16_vk: Vec<u8>,
+    // Disabled configs can't be used for new games, running games still complete
+    pub enabled: bool,
+    pub bump: u8,
+}
+
+impl VerifierConfig {
+    pub fn validate_vkey_hash(vkey_hash: &str) -> Result<()> {
+        require!(
+            vkey_hash.len() == VKEY_HASH_LEN
+                && vkey_hash.starts_with("0x")
+                && vkey_hash[2..].bytes().all(|b| b.is_ascii_hexdigit()),
+            MyError::InvalidVkeyHash
+        );
+        Ok(())
+    }
+
+    pub fn validate_groth16_vk(groth16_vk: &[u8]) -> Result<()> {
+        require!(
+            !groth16_vk.is_empty() && groth16_vk.len() <= GROTH16_VK_MAX_LEN,
+            MyError::InvalidGroth16Vk
+        );
+        Ok(())
+    }
+
+    pub fn verify_proof(
+        &self,
+        proof: &[u8],
+        public_inputs: &[u8],
+    ) -> core::result::Result<(), sp1_solana::Error> {
+        sp1_solana::verify_proof(proof, public_inputs, &self.vkey_hash, &self.groth16_vk)
+    }
+}
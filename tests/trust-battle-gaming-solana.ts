@@ -6,21 +6,30 @@ import {
   LAMPORTS_PER_SOL,
   PublicKey,
   sendAndConfirmTransaction,
+  SystemProgram,
   Transaction,
 } from "@solana/web3.js";
 import * as bip39 from "bip39";
 import {assert} from "chai";
 import {createHash} from "crypto";
 import {
+  createInitializeMintInstruction,
+  createInitializeTransferFeeConfigInstruction,
   createMint,
+  ExtensionType,
+  getMintLen,
   getOrCreateAssociatedTokenAccount,
   mintTo,
+  TOKEN_2022_PROGRAM_ID,
   TOKEN_PROGRAM_ID,
 } from "@solana/spl-token";
 
 const TEST_SEED =
   "vague parrot cook twelve fan flush curve web coffee pet angry mammal";
 const TEST_PUB = "3xoJZkhxuzKpKATL7UhskTA17uBuEnMeuLAqhovETHg4";
+// Test vkey hashes, deployments register the player programs vkey hashes
+const LOGIN_VKEY_HASH = "0x" + "11".repeat(32);
+const WITHDRAW_VKEY_HASH = "0x" + "22".repeat(32);
 const PLAYER1_USERNAME = "player1";
 const PLAYER2_USERNAME = "player2";
 
@@ -66,7 +75,8 @@ const PROOF_P1_G0_C1 = [
 ];
 
 const MIN_AMOUNT = new BN(1_000_000);
-const INIT_PLAYER_BAL = MIN_AMOUNT.mul(new BN(5));
+const MAX_AMOUNT = new BN(1_000_000_000);
+const INIT_PLAYER_BAL = MIN_AMOUNT.mul(new BN(20));
 
 describe("trust-battle-gaming-solana", () => {
   // Configure the client to use the local cluster.
@@ -80,8 +90,13 @@ describe("trust-battle-gaming-solana", () => {
   const gameClient = Keypair.fromSeed(gameClientSeed.subarray(0, 32));
   const platformAccSeed = bip39.mnemonicToSeedSync(TEST_SEED, "123456");
   const platformAcc = Keypair.fromSeed(platformAccSeed.subarray(0, 32));
+  const otherClientSeed = bip39.mnemonicToSeedSync(TEST_SEED, "1234567");
+  const otherClient = Keypair.fromSeed(otherClientSeed.subarray(0, 32));
 
   let managerPda: PublicKey;
+  let rpsVerifierPda: PublicKey;
+  let loginVerifierPda: PublicKey;
+  let withdrawVerifierPda: PublicKey;
   let vault: PublicKey;
   let usdcMint: PublicKey;
 
@@ -93,8 +108,251 @@ describe("trust-battle-gaming-solana", () => {
   let player2PdaAta: PublicKey;
   let player2RpsBasicPda: PublicKey;
   let gameClientAta: PublicKey;
+  let otherClientPda: PublicKey;
   let platformAta: PublicKey;
 
+  // Mint a game is played with and the accounts to move its tokens
+  type GameToken = {
+    mint: PublicKey;
+    tokenProgram: PublicKey;
+    vault: PublicKey;
+  };
+  const usdc = (): GameToken => ({
+    mint: usdcMint,
+    tokenProgram: TOKEN_PROGRAM_ID,
+    vault,
+  });
+
+  // Game ids are assigned by the game client counter
+  const nextGameId = async () =>
+    (await program.account.gameClient.fetch(gameClientPda)).gameCount;
+
+  // sha256(choice || salt || game_id || client) of a hash reveal game
+  const hashChoice = (
+    choice: number,
+    salt: Buffer,
+    roundId: BN,
+    client: PublicKey
+  ) =>
+    Array.from(
+      createHash("sha256")
+        .update(Buffer.from([choice]))
+        .update(salt)
+        .update(roundId.toArrayLike(Buffer, "le", 8))
+        .update(client.toBuffer())
+        .digest()
+    );
+
+  const gamePdaOf = (client: PublicKey, gameId: BN) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("rps_basic_game"),
+        client.toBuffer(),
+        gameId.toArrayLike(Buffer, "le", 8),
+      ],
+      program.programId
+    )[0];
+
+  // Client stake limits are passed even if the client didn't set any
+  const clientMintConfigOf = (mint: PublicKey, client = gameClientPda) =>
+    PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("client_mint_config"),
+        client.toBuffer(),
+        mint.toBuffer(),
+      ],
+      program.programId
+    )[0];
+
+  // Player1 starts a hash reveal game on the game client
+  const initHashGame = async (
+    player1Choice: number,
+    salt: Buffer,
+    {
+      maxRematches = 0,
+      bestOf = 1,
+      opponent = null,
+      token = usdc(),
+    }: {
+      maxRematches?: number;
+      bestOf?: number;
+      opponent?: object | null;
+      token?: GameToken;
+    } = {}
+  ) => {
+    let gameId = await nextGameId();
+
+    await program.methods
+      .initRpsBasic({
+        expectedId: gameId,
+        amount: MIN_AMOUNT,
+        choiceHash: hashChoice(player1Choice, salt, gameId, gameClientPda),
+        revealMode: {hash: {}},
+        maxRematches,
+        bestOf,
+        opponent,
+        loginProof: null,
+      })
+      .accounts({
+        signer: gameClient.publicKey,
+        player1: player1Pda,
+        gameClient: gameClientPda,
+        clientMintConfig: clientMintConfigOf(token.mint),
+        verifierConfig: null,
+        manager: managerPda,
+        ...token,
+      })
+      .signers([gameClient])
+      .rpc();
+
+    return gamePdaOf(gameClientPda, gameId);
+  };
+
+  const joinGame = (
+    gamePda: PublicKey,
+    player2Choice: number,
+    token: GameToken = usdc()
+  ) =>
+    program.methods
+      .joinRpsBasic({
+        player2Choice,
+        loginProof: null,
+      })
+      .accounts({
+        signer: gameClient.publicKey,
+        rpsBasicGame: gamePda,
+        player1: player1Pda,
+        player2: player2Pda,
+        gameClient: gameClientPda,
+        manager: managerPda,
+        ...token,
+      })
+      .signers([gameClient])
+      .rpc();
+
+  const cancelGame = (gamePda: PublicKey) =>
+    program.methods
+      .cancelRpsBasic()
+      .accounts({
+        signer: gameClient.publicKey,
+        rpsBasicGame: gamePda,
+        player1: player1Pda,
+        player1RpsBasic: player1RpsBasicPda,
+        gameClient: gameClientPda,
+        mint: usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        manager: managerPda,
+        vault,
+      })
+      .signers([gameClient])
+      .rpc();
+
+  const setClientFee = (clientFeeBps: number | null) =>
+    program.methods
+      .setGameClientFee(clientFeeBps)
+      .accounts({
+        admin: admin.publicKey,
+        gameClient: gameClientPda,
+      })
+      .signers([admin])
+      .rpc();
+
+  const updateFees = (clientFeeBps: number, platformFeeBps: number) =>
+    program.methods
+      .updateManager({
+        clientFeeBps,
+        platformFeeBps,
+        joinClientShareBps: 5000,
+        platformKey: platformAcc.publicKey,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+  const setVerifierEnabled = (verifierPda: PublicKey, enabled: boolean) =>
+    program.methods
+      .setVerifierEnabled(enabled)
+      .accounts({
+        admin: admin.publicKey,
+        verifierConfig: verifierPda,
+      })
+      .signers([admin])
+      .rpc();
+
+  const tokenBalance = async (ata: PublicKey) =>
+    new BN(
+      (
+        await anchor.getProvider().connection.getTokenAccountBalance(ata)
+      ).value.amount
+    );
+
+  // Player1 starts a hash reveal game and player2 joins it
+  const startHashGame = async (
+    player1Choice: number,
+    player2Choice: number,
+    salt: Buffer,
+    opts: {
+      maxRematches?: number;
+      bestOf?: number;
+      opponent?: object;
+      token?: GameToken;
+    } = {}
+  ) => {
+    let gamePda = await initHashGame(player1Choice, salt, opts);
+    await joinGame(gamePda, player2Choice, opts.token);
+    return gamePda;
+  };
+
+  // Player1 reveals the current round of a hash reveal game
+  const completeHashGame = (
+    gamePda: PublicKey,
+    player1Choice: object,
+    salt: Buffer,
+    nextChoiceHash: number[] | null = null,
+    token: GameToken = usdc()
+  ) =>
+    program.methods
+      .completeRpsBasic({
+        proof: Buffer.alloc(0),
+        salt: Array.from(salt),
+        player1Choice,
+        nextChoiceHash,
+      })
+      .accounts({
+        signer: gameClient.publicKey,
+        rpsBasicGame: gamePda,
+        verifierConfig: null,
+        player1: player1Pda,
+        player1RpsBasicPda,
+        player2: player2Pda,
+        player2RpsBasicPda,
+        rpsBasicResult: null,
+        gameClient: gameClientPda,
+        originClient: gameClientPda,
+        joinClient: gameClientPda,
+        manager: managerPda,
+        ...token,
+      })
+      .signers([gameClient])
+      .rpc();
+
+  // Player2 picks again for the next round of a game
+  const rematchGame = (gamePda: PublicKey, player2Choice: number) =>
+    program.methods
+      .rematchRpsBasic({
+        player2Choice,
+      })
+      .accounts({
+        signer: gameClient.publicKey,
+        rpsBasicGame: gamePda,
+        player2: player2Pda,
+        gameClient: gameClientPda,
+      })
+      .signers([gameClient])
+      .rpc();
+
   before(async () => {
     await anchor
       .getProvider()
@@ -102,6 +360,9 @@ describe("trust-battle-gaming-solana", () => {
     await anchor
       .getProvider()
       .connection.requestAirdrop(gameClient.publicKey, LAMPORTS_PER_SOL * 1000);
+    await anchor
+      .getProvider()
+      .connection.requestAirdrop(otherClient.publicKey, LAMPORTS_PER_SOL * 10);
 
     // Create usdc_mint
     usdcMint = await createMint(
@@ -112,17 +373,26 @@ describe("trust-battle-gaming-solana", () => {
       6
     );
 
+    rpsVerifierPda = PublicKey.findProgramAddressSync(
+      [Buffer.from("verifier_config"), Buffer.from([0]), Buffer.from([0, 0])],
+      program.programId
+    )[0];
+
     // Init program
     await program.methods
       .init({
         clientFeeBps: 50, // 0.5%
         platformFeeBps: 50, // 0.5%
+        joinClientShareBps: 5000, // 50% of client fee
         platformKey: platformAcc.publicKey,
+        minAmount: MIN_AMOUNT,
+        maxAmount: MAX_AMOUNT,
       })
       .accounts({
         admin: admin.publicKey,
-        usdcMint,
+        mint: usdcMint,
         tokenProgram: TOKEN_PROGRAM_ID,
+        rpsBasicVerifier: rpsVerifierPda,
       })
       .signers([admin])
       .rpc();
@@ -142,12 +412,60 @@ describe("trust-battle-gaming-solana", () => {
       )
     ).address;
 
+    // Player circuits, registered by the admin with their SP1 vkey hash
+    let groth16Vk = (await program.account.verifierConfig.fetch(rpsVerifierPda))
+      .groth16Vk;
+    const registerPlayerVerifier = async (
+      circuitType: object,
+      circuitId: number,
+      vkeyHash: string
+    ) => {
+      let [verifierPda] = PublicKey.findProgramAddressSync(
+        [
+          Buffer.from("verifier_config"),
+          Buffer.from([circuitId]),
+          Buffer.from([0, 0]),
+        ],
+        program.programId
+      );
+
+      await program.methods
+        .registerVerifier({
+          circuitType,
+          version: 0,
+          vkeyHash,
+          groth16Vk,
+        })
+        .accounts({
+          admin: admin.publicKey,
+          verifierConfig: verifierPda,
+        })
+        .signers([admin])
+        .rpc();
+
+      return verifierPda;
+    };
+
+    loginVerifierPda = await registerPlayerVerifier(
+      {playerLogin: {}},
+      1,
+      LOGIN_VKEY_HASH
+    );
+    withdrawVerifierPda = await registerPlayerVerifier(
+      {playerWithdraw: {}},
+      2,
+      WITHDRAW_VKEY_HASH
+    );
+
     // Add game client
     await program.methods
       .registerGameClient({
         name: "Test Game Client",
         signer: gameClient.publicKey,
       })
+      .accounts({
+        admin: admin.publicKey,
+      })
       .signers([admin])
       .rpc();
 
@@ -157,6 +475,23 @@ describe("trust-battle-gaming-solana", () => {
       program.programId
     )[0];
 
+    // Second game client, for games played across clients
+    await program.methods
+      .registerGameClient({
+        name: "Other Game Client",
+        signer: otherClient.publicKey,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    otherClientPda = PublicKey.findProgramAddressSync(
+      [Buffer.from("game_client"), otherClient.publicKey.toBuffer()],
+      program.programId
+    )[0];
+
     // Create player1
     await program.methods
       .registerPlayer({
@@ -269,6 +604,89 @@ describe("trust-battle-gaming-solana", () => {
     );
   });
 
+  it("Update manager", async () => {
+    // Fees together must stay below 10000 BPS
+    try {
+      await program.methods
+        .updateManager({
+          clientFeeBps: 5000,
+          platformFeeBps: 5000,
+          joinClientShareBps: 5000,
+          platformKey: platformAcc.publicKey,
+        })
+        .accounts({
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+      assert.fail("Invalid fees were accepted");
+    } catch (e) {
+      assert.include(e.toString(), "InvalidFeeBps");
+    }
+
+    await program.methods
+      .updateManager({
+        clientFeeBps: 50,
+        platformFeeBps: 50,
+        joinClientShareBps: 5000,
+        platformKey: platformAcc.publicKey,
+      })
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    let managerData = await program.account.manager.fetch(managerPda);
+
+    assert(managerData.clientFee === 50, "Client fee was not updated");
+    assert(managerData.platformFee === 50, "Platform fee was not updated");
+  });
+
+  it("Transfer admin", async () => {
+    const newAdmin = Keypair.generate();
+
+    await program.methods
+      .proposeAdmin(newAdmin.publicKey)
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    await program.methods
+      .acceptAdmin()
+      .accounts({
+        pendingAdmin: newAdmin.publicKey,
+      })
+      .signers([newAdmin])
+      .rpc();
+
+    let managerData = await program.account.manager.fetch(managerPda);
+
+    assert(
+      managerData.admin.toString() == newAdmin.publicKey.toString(),
+      "Admin was not transferred"
+    );
+
+    // Transfer the admin back for the rest of the tests
+    await program.methods
+      .proposeAdmin(admin.publicKey)
+      .accounts({
+        admin: newAdmin.publicKey,
+      })
+      .signers([newAdmin])
+      .rpc();
+
+    await program.methods
+      .acceptAdmin()
+      .accounts({
+        pendingAdmin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+  });
+
   it("2 players were created", async () => {
     let player1PdaBalance = await anchor
       .getProvider()
@@ -283,8 +701,161 @@ describe("trust-battle-gaming-solana", () => {
     assert(player2PdaBalance > 0, "Player2 PDA balance is 0");
   });
 
+  it("Pause blocks new players", async () => {
+    await program.methods
+      .setPaused(true)
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    try {
+      await program.methods
+        .registerPlayer({
+          username: "paused_player",
+          loginHash: Array(32).fill(0),
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          gameClient: gameClientPda,
+        })
+        .signers([gameClient])
+        .rpc();
+      assert.fail("Player was registered while paused");
+    } catch (e) {
+      assert.include(e.toString(), "Paused");
+    }
+
+    await program.methods
+      .setPaused(false)
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+  });
+
+  it("Suspended game client is rejected", async () => {
+    await program.methods
+      .setGameClientSuspended(true)
+      .accounts({
+        admin: admin.publicKey,
+        gameClient: gameClientPda,
+      })
+      .signers([admin])
+      .rpc();
+
+    try {
+      await program.methods
+        .registerPlayer({
+          username: "suspended_player",
+          loginHash: Array(32).fill(0),
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          gameClient: gameClientPda,
+        })
+        .signers([gameClient])
+        .rpc();
+      assert.fail("Suspended game client registered a player");
+    } catch (e) {
+      assert.include(e.toString(), "GameClientSuspended");
+    }
+
+    await program.methods
+      .setGameClientSuspended(false)
+      .accounts({
+        admin: admin.publicKey,
+        gameClient: gameClientPda,
+      })
+      .signers([admin])
+      .rpc();
+  });
+
+  it("Login proof setting can only be changed with a login proof", async () => {
+    const setLoginProof = (loginVerifier: PublicKey) =>
+      program.methods
+        .setPlayerLoginProof({
+          requireLoginProof: true,
+          loginProof: Buffer.alloc(260),
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          player: player1Pda,
+          gameClient: gameClientPda,
+          loginVerifier,
+        })
+        .signers([gameClient])
+        .rpc();
+
+    try {
+      await setLoginProof(rpsVerifierPda);
+      assert.fail("Login proof was verified with the RPS verifier");
+    } catch (e) {
+      assert.include(e.toString(), "VerifierCircuitTypeMismatch");
+    }
+
+    try {
+      await setLoginProof(loginVerifierPda);
+      assert.fail("Game client changed the setting without a valid proof");
+    } catch (e) {
+      assert.include(e.toString(), "PlayerProofVerify");
+    }
+
+    let playerData = await program.account.player.fetch(player1Pda);
+    assert(!playerData.requireLoginProof, "Login proof should stay optional");
+  });
+
+  it("Withdraw needs a valid player proof", async () => {
+    const withdraw = (withdrawVerifier: PublicKey) =>
+      program.methods
+        .withdrawPlayer({
+          proof: Buffer.alloc(260),
+          amount: MIN_AMOUNT,
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          player: player1Pda,
+          playerAta: player1PdaAta,
+          destination: gameClientAta,
+          manager: managerPda,
+          mint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          withdrawVerifier,
+        })
+        .signers([gameClient])
+        .rpc();
+
+    try {
+      await withdraw(loginVerifierPda);
+      assert.fail("Withdraw proof was verified with the login verifier");
+    } catch (e) {
+      assert.include(e.toString(), "VerifierCircuitTypeMismatch");
+    }
+
+    try {
+      await withdraw(withdrawVerifierPda);
+      assert.fail("Player funds were withdrawn without a valid proof");
+    } catch (e) {
+      assert.include(e.toString(), "PlayerProofVerify");
+    }
+
+    let playerBalance = (
+      await anchor
+        .getProvider()
+        .connection.getTokenAccountBalance(player1PdaAta)
+    ).value.amount;
+    assert(
+      playerBalance === INIT_PLAYER_BAL.toString(),
+      "Player balance should be untouched"
+    );
+  });
+
   it("Player RPS basic game", async () => {
-    let gameId = new BN(0);
+    // Game ids are assigned by the game client counter
+    let gameId = (await program.account.gameClient.fetch(gameClientPda))
+      .gameCount;
     let player1Choice = 1;
 
     // Register player1 to rps_basic
@@ -330,15 +901,23 @@ describe("trust-battle-gaming-solana", () => {
 
     await program.methods
       .initRpsBasic({
-        id: gameId,
+        expectedId: gameId,
         amount: MIN_AMOUNT,
         choiceHash: Array.from(choice_hash),
+        revealMode: {zk: {}},
+        maxRematches: 0,
+        bestOf: 1,
+        opponent: null,
+        loginProof: null,
       })
       .accounts({
         signer: gameClient.publicKey,
         player1: player1Pda,
         gameClient: gameClientPda,
-        usdcMint,
+        clientMintConfig: clientMintConfigOf(usdcMint),
+        verifierConfig: rpsVerifierPda,
+        mint: usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
         manager: managerPda,
         vault,
       })
@@ -361,6 +940,7 @@ describe("trust-battle-gaming-solana", () => {
     await program.methods
       .joinRpsBasic({
         player2Choice: 0,
+        loginProof: null,
       })
       .accounts({
         signer: gameClient.publicKey,
@@ -368,7 +948,8 @@ describe("trust-battle-gaming-solana", () => {
         player1: player1Pda,
         player2: player2Pda,
         gameClient: gameClientPda,
-        usdcMint,
+        mint: usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
         manager: managerPda,
         vault,
       })
@@ -379,6 +960,12 @@ describe("trust-battle-gaming-solana", () => {
     let gameData = await program.account.rpsBasicGame.fetch(gamePda);
 
     assert(gameData.player2, "Player2 did not join the game");
+    assert(
+      gameData.player1Deposit
+        .add(gameData.player2Deposit)
+        .eq(MIN_AMOUNT.muln(2)),
+      "Game pot doesn't match both stakes"
+    );
 
     // Confirm the vault holds right amount
     let vaultBalance = (
@@ -390,6 +977,19 @@ describe("trust-battle-gaming-solana", () => {
       "Vault balance is wrong"
     );
 
+    // Running games keep verifying with their pinned verifier once disabled
+    await setVerifierEnabled(rpsVerifierPda, false);
+
+    // Keep a receipt of the game after it completes
+    let [resultPda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("rps_basic_result"),
+        gameClientPda.toBuffer(),
+        gameId.toBuffer("le", 8),
+      ],
+      program.programId
+    );
+
     // we need to increase our max CU for verification
     let ix = anchor.web3.ComputeBudgetProgram.setComputeUnitLimit({
       units: 400000,
@@ -399,17 +999,24 @@ describe("trust-battle-gaming-solana", () => {
     let completeIx = await program.methods
       .completeRpsBasic({
         proof: Buffer.from(PROOF_P1_G0_C1),
-        player1Choice,
+        salt: null,
+        player1Choice: {paper: {}},
+        nextChoiceHash: null,
       })
       .accounts({
         signer: gameClient.publicKey,
         rpsBasicGame: gamePda,
+        verifierConfig: rpsVerifierPda,
         player1: player1Pda,
         player1RpsBasicPda,
         player2: player2Pda,
         player2RpsBasicPda,
+        rpsBasicResult: resultPda,
         gameClient: gameClientPda,
-        usdcMint,
+        originClient: gameClientPda,
+        joinClient: gameClientPda,
+        mint: usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
         manager: managerPda,
         vault,
       })
@@ -422,6 +1029,17 @@ describe("trust-battle-gaming-solana", () => {
       gameClient,
     ]);
 
+    await setVerifierEnabled(rpsVerifierPda, true);
+
+    // Receipt outlives the game account
+    let resultData = await program.account.rpsBasicResult.fetch(resultPda);
+
+    assert(
+      resultData.player1.toString() == player1Pda.toString(),
+      "Receipt player1 is wrong"
+    );
+    assert(resultData.result.player1 !== undefined, "Receipt result is wrong");
+
     // Player 1 won, so player1RpsBasicPda should contain data about the win
     // and player2RpsBasicPda should contain data about the loss
     let player1RpsBasicData = await program.account.rpsBasicPlayer.fetch(
@@ -449,6 +1067,11 @@ describe("trust-battle-gaming-solana", () => {
       "Player2 should have 1 game lost"
     );
 
+    assert(
+      player1RpsBasicData.totalRounds.cmp(new BN(1)) === 0,
+      "Player1 should have 1 round played"
+    );
+
     assert(
       player1RpsBasicData.totalChoices[1].cmp(new BN(1)) === 0,
       "Player1 chose choice 1"
@@ -458,6 +1081,16 @@ describe("trust-battle-gaming-solana", () => {
       "Player2 chose choice 0"
     );
 
+    // Both players started at 1200.00, so the winner gains exactly K / 2 = 16 points
+    assert(
+      player1RpsBasicData.rating === 121600,
+      "Player1 rating should be 1216.00"
+    );
+    assert(
+      player2RpsBasicData.rating === 118400,
+      "Player2 rating should be 1184.00"
+    );
+
     // Vault balance should be zero because we took everything out
     let newVaultBalance = (
       await anchor.getProvider().connection.getTokenAccountBalance(vault)
@@ -517,7 +1150,8 @@ describe("trust-battle-gaming-solana", () => {
   });
 
   it("Cancel game", async () => {
-    let gameId = new BN(1);
+    let gameId = (await program.account.gameClient.fetch(gameClientPda))
+      .gameCount;
     let player1Choice = 1;
 
     let player1PdaAtaBalanaceInit = parseInt(
@@ -546,15 +1180,23 @@ describe("trust-battle-gaming-solana", () => {
     // Start new game to cancel later
     await program.methods
       .initRpsBasic({
-        id: gameId,
+        expectedId: gameId,
         amount: MIN_AMOUNT,
         choiceHash: Array.from(choice_hash),
+        revealMode: {zk: {}},
+        maxRematches: 0,
+        bestOf: 1,
+        opponent: null,
+        loginProof: null,
       })
       .accounts({
         signer: gameClient.publicKey,
         player1: player1Pda,
         gameClient: gameClientPda,
-        usdcMint,
+        clientMintConfig: clientMintConfigOf(usdcMint),
+        verifierConfig: rpsVerifierPda,
+        mint: usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
         manager: managerPda,
         vault,
       })
@@ -570,21 +1212,33 @@ describe("trust-battle-gaming-solana", () => {
       program.programId
     );
 
+    const cancel = (player: PublicKey, playerRpsBasic: PublicKey) =>
+      program.methods
+        .cancelRpsBasic()
+        .accounts({
+          signer: gameClient.publicKey,
+          rpsBasicGame: gamePda,
+          player1: player,
+          player1RpsBasic: playerRpsBasic,
+          gameClient: gameClientPda,
+          mint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          manager: managerPda,
+          vault,
+        })
+        .signers([gameClient])
+        .rpc();
+
+    // Player1 stake can't be refunded to another player
+    try {
+      await cancel(player2Pda, player2RpsBasicPda);
+      assert.fail("Game was cancelled to another player");
+    } catch (e) {
+      assert.include(e.toString(), "RpsBasicPlayer1Mismatch");
+    }
+
     // Cancle game
-    await program.methods
-      .cancelRpsBasic()
-      .accounts({
-        signer: gameClient.publicKey,
-        rpsBasicGame: gamePda,
-        player1: player1Pda,
-        player1RpsBasic: player1RpsBasicPda,
-        gameClient: gameClientPda,
-        usdcMint,
-        manager: managerPda,
-        vault,
-      })
-      .signers([gameClient])
-      .rpc();
+    await cancel(player1Pda, player1RpsBasicPda);
 
     let fee = MIN_AMOUNT.mul(new BN(50)).div(new BN(10000));
 
@@ -617,4 +1271,1615 @@ describe("trust-battle-gaming-solana", () => {
       "Client doesn't hold fee amount"
     );
   });
+
+  it("Invite-only game rejects other players", async () => {
+    let gameId = (await program.account.gameClient.fetch(gameClientPda))
+      .gameCount;
+
+    let choice_hash = createHash("sha256")
+      .update(Buffer.from(TEST_SECRET))
+      .update(gameClientPda.toString())
+      .update(gameId.toString())
+      .update("1")
+      .digest();
+
+    // Only a player named "invited" can join this game
+    let invitedHash = createHash("sha256").update("invited").digest();
+
+    await program.methods
+      .initRpsBasic({
+        expectedId: gameId,
+        amount: MIN_AMOUNT,
+        choiceHash: Array.from(choice_hash),
+        revealMode: {zk: {}},
+        maxRematches: 0,
+        bestOf: 1,
+        opponent: {usernameHash: {0: Array.from(invitedHash)}},
+        loginProof: null,
+      })
+      .accounts({
+        signer: gameClient.publicKey,
+        player1: player1Pda,
+        gameClient: gameClientPda,
+        clientMintConfig: clientMintConfigOf(usdcMint),
+        verifierConfig: rpsVerifierPda,
+        mint: usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        manager: managerPda,
+        vault,
+      })
+      .signers([gameClient])
+      .rpc();
+
+    let [gamePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("rps_basic_game"),
+        gameClientPda.toBuffer(),
+        gameId.toBuffer("le", 8),
+      ],
+      program.programId
+    );
+
+    try {
+      await program.methods
+        .joinRpsBasic({
+          player2Choice: 0,
+          loginProof: null,
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          rpsBasicGame: gamePda,
+          player1: player1Pda,
+          player2: player2Pda,
+          gameClient: gameClientPda,
+          mint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          manager: managerPda,
+          vault,
+        })
+        .signers([gameClient])
+        .rpc();
+      assert.fail("Player2 joined a game he wasn't invited to");
+    } catch (e) {
+      assert.include(e.toString(), "RpsBasicNotInvited");
+    }
+
+    // Nobody joined, player1 can still cancel
+    await program.methods
+      .cancelRpsBasic()
+      .accounts({
+        signer: gameClient.publicKey,
+        rpsBasicGame: gamePda,
+        player1: player1Pda,
+        player1RpsBasic: player1RpsBasicPda,
+        gameClient: gameClientPda,
+        mint: usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        manager: managerPda,
+        vault,
+      })
+      .signers([gameClient])
+      .rpc();
+  });
+
+  it("Invited player joins the game", async () => {
+    let salt = Buffer.alloc(32, 19);
+    let usernameHash = createHash("sha256").update(PLAYER2_USERNAME).digest();
+
+    for (let opponent of [
+      {player: {0: player2Pda}},
+      {usernameHash: {0: Array.from(usernameHash)}},
+    ]) {
+      let gamePda = await startHashGame(1, 0, salt, {opponent});
+
+      let gameData = await program.account.rpsBasicGame.fetch(gamePda);
+      assert(
+        gameData.player2.key.equals(player2Pda),
+        "Invited player should join the game"
+      );
+
+      await completeHashGame(gamePda, {paper: {}}, salt);
+    }
+  });
+
+  it("Game id must be the next id of the client", async () => {
+    // Another game took the id player1 committed his hash to
+    try {
+      await program.methods
+        .initRpsBasic({
+          expectedId: (await nextGameId()).addn(1),
+          amount: MIN_AMOUNT,
+          choiceHash: Array(32).fill(0),
+          revealMode: {zk: {}},
+          maxRematches: 0,
+          bestOf: 1,
+          opponent: null,
+          loginProof: null,
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          player1: player1Pda,
+          gameClient: gameClientPda,
+          clientMintConfig: clientMintConfigOf(usdcMint),
+          verifierConfig: rpsVerifierPda,
+          mint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          manager: managerPda,
+          vault,
+        })
+        .signers([gameClient])
+        .rpc();
+      assert.fail("Game was created with a stale game id");
+    } catch (e) {
+      assert.include(e.toString(), "RpsBasicGameIdMismatch");
+    }
+  });
+
+  it("Series length must be odd", async () => {
+    try {
+      await program.methods
+        .initRpsBasic({
+          expectedId: await nextGameId(),
+          amount: MIN_AMOUNT,
+          choiceHash: Array(32).fill(0),
+          revealMode: {zk: {}},
+          maxRematches: 0,
+          bestOf: 2,
+          opponent: null,
+          loginProof: null,
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          player1: player1Pda,
+          gameClient: gameClientPda,
+          clientMintConfig: clientMintConfigOf(usdcMint),
+          verifierConfig: rpsVerifierPda,
+          mint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          manager: managerPda,
+          vault,
+        })
+        .signers([gameClient])
+        .rpc();
+      assert.fail("Game was created with an even series length");
+    } catch (e) {
+      assert.include(e.toString(), "RpsBasicInvalidBestOf");
+    }
+  });
+
+  it("Disabled mint can't be used for new games", async () => {
+    let otherMint = await createMint(
+      anchor.getProvider().connection,
+      admin,
+      admin.publicKey,
+      admin.publicKey,
+      9
+    );
+
+    await program.methods
+      .registerMint(MIN_AMOUNT, MAX_AMOUNT)
+      .accounts({
+        admin: admin.publicKey,
+        mint: otherMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+
+    let [mintConfigPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("mint_config"), otherMint.toBuffer()],
+      program.programId
+    );
+
+    await program.methods
+      .updateMint({
+        minAmount: MIN_AMOUNT,
+        maxAmount: MAX_AMOUNT,
+        enabled: false,
+      })
+      .accounts({
+        admin: admin.publicKey,
+        mintConfig: mintConfigPda,
+      })
+      .signers([admin])
+      .rpc();
+
+    let mintConfig = await program.account.mintConfig.fetch(mintConfigPda);
+    assert(!mintConfig.enabled, "Mint should be disabled");
+
+    await getOrCreateAssociatedTokenAccount(
+      anchor.getProvider().connection,
+      admin,
+      otherMint,
+      player1Pda,
+      true
+    );
+
+    let otherVault = (
+      await getOrCreateAssociatedTokenAccount(
+        anchor.getProvider().connection,
+        admin,
+        otherMint,
+        managerPda,
+        true
+      )
+    ).address;
+
+    try {
+      await program.methods
+        .initRpsBasic({
+          expectedId: await nextGameId(),
+          amount: MIN_AMOUNT,
+          choiceHash: Array(32).fill(0),
+          revealMode: {zk: {}},
+          maxRematches: 0,
+          bestOf: 1,
+          opponent: null,
+          loginProof: null,
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          player1: player1Pda,
+          gameClient: gameClientPda,
+          clientMintConfig: clientMintConfigOf(otherMint),
+          verifierConfig: rpsVerifierPda,
+          mint: otherMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          manager: managerPda,
+          vault: otherVault,
+        })
+        .signers([gameClient])
+        .rpc();
+      assert.fail("Game was created with a disabled mint");
+    } catch (e) {
+      assert.include(e.toString(), "MintDisabled");
+    }
+  });
+
+  it("SOL game can be cancelled", async () => {
+    const SOL_MINT = PublicKey.default;
+    const SOL_AMOUNT = new BN(LAMPORTS_PER_SOL);
+
+    await program.methods
+      .registerSol(SOL_AMOUNT, SOL_AMOUNT.muln(10))
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    let [solVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sol_vault")],
+      program.programId
+    );
+
+    // SOL stakes are taken from the lamports of the player account
+    await sendAndConfirmTransaction(
+      anchor.getProvider().connection,
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: admin.publicKey,
+          toPubkey: player1Pda,
+          lamports: LAMPORTS_PER_SOL * 2,
+        })
+      ),
+      [admin]
+    );
+
+    let gameId = (await program.account.gameClient.fetch(gameClientPda))
+      .gameCount;
+
+    await program.methods
+      .initRpsBasicSol({
+        expectedId: gameId,
+        amount: SOL_AMOUNT,
+        choiceHash: Array(32).fill(0),
+        revealMode: {zk: {}},
+        maxRematches: 0,
+        bestOf: 1,
+        opponent: null,
+        loginProof: null,
+      })
+      .accounts({
+        signer: gameClient.publicKey,
+        player1: player1Pda,
+        gameClient: gameClientPda,
+        clientMintConfig: clientMintConfigOf(PublicKey.default),
+        verifierConfig: rpsVerifierPda,
+        manager: managerPda,
+      })
+      .signers([gameClient])
+      .rpc();
+
+    let [gamePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("rps_basic_game"),
+        gameClientPda.toBuffer(),
+        gameId.toBuffer("le", 8),
+      ],
+      program.programId
+    );
+
+    let gameData = await program.account.rpsBasicGame.fetch(gamePda);
+    assert(gameData.mint.equals(SOL_MINT), "Game should be played with SOL");
+
+    let vaultBalanceInit = await anchor
+      .getProvider()
+      .connection.getBalance(solVaultPda);
+    let player1BalanceInit = await anchor
+      .getProvider()
+      .connection.getBalance(player1Pda);
+
+    const cancelSol = (player: PublicKey, playerRpsBasic: PublicKey) =>
+      program.methods
+        .cancelRpsBasicSol()
+        .accounts({
+          signer: gameClient.publicKey,
+          rpsBasicGame: gamePda,
+          player1: player,
+          player1RpsBasic: playerRpsBasic,
+          gameClient: gameClientPda,
+        })
+        .signers([gameClient])
+        .rpc();
+
+    // Player1 lamports can't be refunded to another player
+    try {
+      await cancelSol(player2Pda, player2RpsBasicPda);
+      assert.fail("SOL game was cancelled to another player");
+    } catch (e) {
+      assert.include(e.toString(), "RpsBasicPlayer1Mismatch");
+    }
+
+    await cancelSol(player1Pda, player1RpsBasicPda);
+
+    let fee = SOL_AMOUNT.mul(new BN(50)).div(new BN(10000));
+
+    let vaultBalance = await anchor
+      .getProvider()
+      .connection.getBalance(solVaultPda);
+    let player1Balance = await anchor
+      .getProvider()
+      .connection.getBalance(player1Pda);
+
+    assert(
+      vaultBalanceInit - vaultBalance === SOL_AMOUNT.toNumber(),
+      "Vault should release the whole stake"
+    );
+    assert(
+      player1Balance - player1BalanceInit === SOL_AMOUNT.sub(fee).toNumber(),
+      "Player1 should get his stake back minus the client fee"
+    );
+  });
+
+  it("SOL game fees must cover rent", async () => {
+    // Client fee override of 0.01%, fee shares of a 1 SOL game are below rent
+    await setClientFee(1);
+
+    try {
+      await program.methods
+        .initRpsBasicSol({
+          expectedId: await nextGameId(),
+          amount: new BN(LAMPORTS_PER_SOL),
+          choiceHash: Array(32).fill(0),
+          revealMode: {hash: {}},
+          maxRematches: 0,
+          bestOf: 1,
+          opponent: null,
+          loginProof: null,
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          player1: player1Pda,
+          gameClient: gameClientPda,
+          clientMintConfig: clientMintConfigOf(PublicKey.default),
+          verifierConfig: null,
+          manager: managerPda,
+        })
+        .signers([gameClient])
+        .rpc();
+      assert.fail("SOL game was created with fees below rent");
+    } catch (e) {
+      assert.include(e.toString(), "SolStakeBelowRent");
+    }
+
+    await setClientFee(null);
+  });
+
+  it("SOL game is joined, refunded and completed", async () => {
+    const connection = anchor.getProvider().connection;
+    const SOL_AMOUNT = new BN(LAMPORTS_PER_SOL);
+    let [solVaultPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("sol_vault")],
+      program.programId
+    );
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: admin.publicKey,
+          toPubkey: player2Pda,
+          lamports: LAMPORTS_PER_SOL * 2,
+        })
+      ),
+      [admin]
+    );
+
+    const startSolGame = async (salt: Buffer) => {
+      let gameId = await nextGameId();
+      await program.methods
+        .initRpsBasicSol({
+          expectedId: gameId,
+          amount: SOL_AMOUNT,
+          choiceHash: hashChoice(1, salt, gameId, gameClientPda),
+          revealMode: {hash: {}},
+          maxRematches: 0,
+          bestOf: 1,
+          opponent: null,
+          loginProof: null,
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          player1: player1Pda,
+          gameClient: gameClientPda,
+          clientMintConfig: clientMintConfigOf(PublicKey.default),
+          verifierConfig: null,
+          manager: managerPda,
+        })
+        .signers([gameClient])
+        .rpc();
+
+      let gamePda = gamePdaOf(gameClientPda, gameId);
+      await program.methods
+        .joinRpsBasicSol({
+          player2Choice: 0,
+          loginProof: null,
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          rpsBasicGame: gamePda,
+          player1: player1Pda,
+          player2: player2Pda,
+          gameClient: gameClientPda,
+          manager: managerPda,
+        })
+        .signers([gameClient])
+        .rpc();
+      return gamePda;
+    };
+
+    // Game timeout is a month out, so it can't be claimed yet
+    let gamePda = await startSolGame(Buffer.alloc(32, 16));
+    try {
+      await program.methods
+        .claimTimeoutRpsBasicSol()
+        .accounts({
+          signer: gameClient.publicKey,
+          rpsBasicGame: gamePda,
+          player1: player1Pda,
+          player1RpsBasic: player1RpsBasicPda,
+          player2: player2Pda,
+          player2RpsBasic: player2RpsBasicPda,
+          gameClient: gameClientPda,
+          originClient: gameClientPda,
+          originClientSigner: gameClient.publicKey,
+          joinClient: gameClientPda,
+          joinClientSigner: gameClient.publicKey,
+          manager: managerPda,
+          platform: platformAcc.publicKey,
+        })
+        .signers([gameClient])
+        .rpc();
+      assert.fail("Game timeout was claimed before the timeout");
+    } catch (e) {
+      assert.include(e.toString(), "RpsBasicTimeoutNotReached");
+    }
+
+    // Paused platform refunds both stakes without fees
+    let player1Balance = await connection.getBalance(player1Pda);
+    let player2Balance = await connection.getBalance(player2Pda);
+    await program.methods
+      .setPaused(true)
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+    await program.methods
+      .emergencyRefundRpsBasicSol()
+      .accounts({
+        admin: admin.publicKey,
+        rpsBasicGame: gamePda,
+        player1: player1Pda,
+        player2: player2Pda,
+        originClient: gameClientPda,
+        joinClient: gameClientPda,
+        manager: managerPda,
+      })
+      .signers([admin])
+      .rpc();
+    await program.methods
+      .setPaused(false)
+      .accounts({
+        admin: admin.publicKey,
+      })
+      .signers([admin])
+      .rpc();
+
+    assert(
+      (await connection.getBalance(player1Pda)) - player1Balance ===
+        SOL_AMOUNT.toNumber(),
+      "Player1 should get his whole stake back"
+    );
+    assert(
+      (await connection.getBalance(player2Pda)) - player2Balance ===
+        SOL_AMOUNT.toNumber(),
+      "Player2 should get his whole stake back"
+    );
+
+    // Player1 paper beats player2 rock
+    let salt = Buffer.alloc(32, 17);
+    gamePda = await startSolGame(salt);
+    let vaultBalance = await connection.getBalance(solVaultPda);
+    player1Balance = await connection.getBalance(player1Pda);
+    let platformBalance = await connection.getBalance(platformAcc.publicKey);
+
+    await program.methods
+      .completeRpsBasicSol({
+        proof: Buffer.alloc(0),
+        salt: Array.from(salt),
+        player1Choice: {paper: {}},
+        nextChoiceHash: null,
+      })
+      .accounts({
+        signer: gameClient.publicKey,
+        rpsBasicGame: gamePda,
+        verifierConfig: null,
+        rpsBasicResult: null,
+        player1: player1Pda,
+        player1RpsBasic: player1RpsBasicPda,
+        player2: player2Pda,
+        player2RpsBasic: player2RpsBasicPda,
+        gameClient: gameClientPda,
+        originClient: gameClientPda,
+        originClientSigner: gameClient.publicKey,
+        joinClient: gameClientPda,
+        joinClientSigner: gameClient.publicKey,
+        manager: managerPda,
+        platform: platformAcc.publicKey,
+      })
+      .signers([gameClient])
+      .rpc();
+
+    let pot = SOL_AMOUNT.muln(2);
+    let fee = pot.muln(50).divn(10000);
+    assert(
+      vaultBalance - (await connection.getBalance(solVaultPda)) ===
+        pot.toNumber(),
+      "Vault should pay out the whole pot"
+    );
+    assert(
+      (await connection.getBalance(player1Pda)) - player1Balance ===
+        pot.sub(fee.muln(2)).toNumber(),
+      "Player1 should win the pot minus fees"
+    );
+    assert(
+      (await connection.getBalance(platformAcc.publicKey)) - platformBalance ===
+        fee.toNumber(),
+      "Platform should get its fee"
+    );
+  });
+
+  it("SOL withdraw needs a valid player proof", async () => {
+    const withdrawSol = (withdrawVerifier: PublicKey) =>
+      program.methods
+        .withdrawPlayerSol({
+          proof: Buffer.alloc(260),
+          amount: new BN(LAMPORTS_PER_SOL),
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          player: player1Pda,
+          destination: gameClient.publicKey,
+          withdrawVerifier,
+        })
+        .signers([gameClient])
+        .rpc();
+
+    try {
+      await withdrawSol(loginVerifierPda);
+      assert.fail("Withdraw proof was verified with the login verifier");
+    } catch (e) {
+      assert.include(e.toString(), "VerifierCircuitTypeMismatch");
+    }
+
+    try {
+      await withdrawSol(withdrawVerifierPda);
+      assert.fail("Player lamports were withdrawn without a valid proof");
+    } catch (e) {
+      assert.include(e.toString(), "PlayerProofVerify");
+    }
+  });
+
+  it("Client stake limits narrow the mint limits", async () => {
+    await program.methods
+      .setClientStakeLimits(usdcMint, MIN_AMOUNT, MIN_AMOUNT.muln(2))
+      .accounts({
+        signer: gameClient.publicKey,
+        gameClient: gameClientPda,
+      })
+      .signers([gameClient])
+      .rpc();
+
+    // Starts a usdc game on a game client, its stake limits PDA is required
+    const initGame = async (
+      amount: BN,
+      client: Keypair,
+      clientPda: PublicKey,
+      clientMintConfig: PublicKey
+    ) =>
+      program.methods
+        .initRpsBasic({
+          expectedId: (await program.account.gameClient.fetch(clientPda))
+            .gameCount,
+          amount,
+          choiceHash: Array(32).fill(0),
+          revealMode: {zk: {}},
+          maxRematches: 0,
+          bestOf: 1,
+          opponent: null,
+          loginProof: null,
+        })
+        .accounts({
+          signer: client.publicKey,
+          player1: player1Pda,
+          gameClient: clientPda,
+          clientMintConfig,
+          verifierConfig: rpsVerifierPda,
+          mint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          manager: managerPda,
+          vault,
+        })
+        .signers([client])
+        .rpc();
+
+    try {
+      await initGame(
+        MIN_AMOUNT.muln(3),
+        gameClient,
+        gameClientPda,
+        clientMintConfigOf(usdcMint)
+      );
+      assert.fail("Game was created above the client maximum stake");
+    } catch (e) {
+      assert.include(e.toString(), "RpsBasicAmountTooHigh");
+    }
+
+    // Client limits can't be skipped by passing another account
+    try {
+      await initGame(
+        MIN_AMOUNT.muln(3),
+        gameClient,
+        gameClientPda,
+        clientMintConfigOf(usdcMint, otherClientPda)
+      );
+      assert.fail("Game was created without the client stake limits");
+    } catch (e) {
+      assert.include(e.toString(), "ConstraintSeeds");
+    }
+
+    // Other client has no limits of its own, so the mint limits apply
+    let otherClientMintConfig = clientMintConfigOf(usdcMint, otherClientPda);
+    try {
+      await initGame(
+        MAX_AMOUNT.addn(1),
+        otherClient,
+        otherClientPda,
+        otherClientMintConfig
+      );
+      assert.fail("Game was created above the mint maximum stake");
+    } catch (e) {
+      assert.include(e.toString(), "RpsBasicAmountTooHigh");
+    }
+
+    try {
+      await initGame(
+        MIN_AMOUNT.subn(1),
+        otherClient,
+        otherClientPda,
+        otherClientMintConfig
+      );
+      assert.fail("Game was created below the mint minimum stake");
+    } catch (e) {
+      assert.include(e.toString(), "RpsBasicAmountTooLow");
+    }
+  });
+
+  it("Hash reveal game completes without a proof", async () => {
+    let gameId = (await program.account.gameClient.fetch(gameClientPda))
+      .gameCount;
+    let salt = Buffer.alloc(32, 7);
+
+    // sha256(choice || salt || game_id || client)
+    let choiceHash = createHash("sha256")
+      .update(Buffer.from([1]))
+      .update(salt)
+      .update(gameId.toBuffer("le", 8))
+      .update(gameClientPda.toBuffer())
+      .digest();
+
+    await program.methods
+      .initRpsBasic({
+        expectedId: gameId,
+        amount: MIN_AMOUNT,
+        choiceHash: Array.from(choiceHash),
+        revealMode: {hash: {}},
+        maxRematches: 0,
+        bestOf: 1,
+        opponent: null,
+        loginProof: null,
+      })
+      .accounts({
+        signer: gameClient.publicKey,
+        player1: player1Pda,
+        gameClient: gameClientPda,
+        clientMintConfig: clientMintConfigOf(usdcMint),
+        verifierConfig: null,
+        mint: usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        manager: managerPda,
+        vault,
+      })
+      .signers([gameClient])
+      .rpc();
+
+    let [gamePda] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("rps_basic_game"),
+        gameClientPda.toBuffer(),
+        gameId.toBuffer("le", 8),
+      ],
+      program.programId
+    );
+
+    await program.methods
+      .joinRpsBasic({
+        player2Choice: 0,
+        loginProof: null,
+      })
+      .accounts({
+        signer: gameClient.publicKey,
+        rpsBasicGame: gamePda,
+        player1: player1Pda,
+        player2: player2Pda,
+        gameClient: gameClientPda,
+        mint: usdcMint,
+        tokenProgram: TOKEN_PROGRAM_ID,
+        manager: managerPda,
+        vault,
+      })
+      .signers([gameClient])
+      .rpc();
+
+    const completeGame = (salt: Buffer) =>
+      program.methods
+        .completeRpsBasic({
+          proof: Buffer.alloc(0),
+          salt: Array.from(salt),
+          player1Choice: {paper: {}},
+          nextChoiceHash: null,
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          rpsBasicGame: gamePda,
+          verifierConfig: null,
+          player1: player1Pda,
+          player1RpsBasicPda,
+          player2: player2Pda,
+          player2RpsBasicPda,
+          rpsBasicResult: null,
+          gameClient: gameClientPda,
+          originClient: gameClientPda,
+          joinClient: gameClientPda,
+          mint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          manager: managerPda,
+          vault,
+        })
+        .signers([gameClient])
+        .rpc();
+
+    try {
+      await completeGame(Buffer.alloc(32, 8));
+      assert.fail("Game was completed with a wrong salt");
+    } catch (e) {
+      assert.include(e.toString(), "RpsBasicHashMismatch");
+    }
+
+    await completeGame(salt);
+
+    let gameData = await program.account.rpsBasicGame.fetchNullable(gamePda);
+    assert(gameData === null, "Game should be closed once completed");
+  });
+
+  it("Only the game clients of a game can claim its timeout", async () => {
+    let salt = Buffer.alloc(32, 9);
+    let gamePda = await startHashGame(1, 0, salt);
+
+    try {
+      await program.methods
+        .claimTimeoutRpsBasic()
+        .accounts({
+          signer: otherClient.publicKey,
+          rpsBasicGame: gamePda,
+          player1: player1Pda,
+          player1RpsBasic: player1RpsBasicPda,
+          player1Ata: player1PdaAta,
+          player2: player2Pda,
+          player2RpsBasic: player2RpsBasicPda,
+          player2Ata: player2PdaAta,
+          gameClient: otherClientPda,
+          originClient: gameClientPda,
+          originClientAta: gameClientAta,
+          joinClient: gameClientPda,
+          joinClientAta: gameClientAta,
+          manager: managerPda,
+          mint: usdcMint,
+          vault,
+          platformAta,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([otherClient])
+        .rpc();
+      assert.fail("Game timeout was claimed by an unrelated game client");
+    } catch (e) {
+      assert.include(e.toString(), "RpsBasicClientMismatch");
+    }
+
+    await completeHashGame(gamePda, {paper: {}}, salt);
+  });
+
+  it("Drawn game is replayed with a rematch", async () => {
+    let salt = Buffer.alloc(32, 10);
+    let nextSalt = Buffer.alloc(32, 11);
+    let statsBefore = await program.account.rpsBasicPlayer.fetch(
+      player1RpsBasicPda
+    );
+    let gamePda = await startHashGame(0, 0, salt, {maxRematches: 1});
+    let gameId = (await program.account.rpsBasicGame.fetch(gamePda)).id;
+
+    // Rock against rock, player1 commits to paper for the second round
+    let roundId = gameId.or(new BN(1).shln(56));
+    await completeHashGame(
+      gamePda,
+      {rock: {}},
+      salt,
+      hashChoice(1, nextSalt, roundId, gameClientPda)
+    );
+
+    let gameData = await program.account.rpsBasicGame.fetch(gamePda);
+    assert(gameData.round === 1, "Game should move to the second round");
+    assert(gameData.rematchesLeft === 0, "Rematch should be used");
+
+    // Only the client player2 joined from can submit his pick
+    try {
+      await program.methods
+        .rematchRpsBasic({
+          player2Choice: 0,
+        })
+        .accounts({
+          signer: otherClient.publicKey,
+          rpsBasicGame: gamePda,
+          player2: player2Pda,
+          gameClient: otherClientPda,
+        })
+        .signers([otherClient])
+        .rpc();
+      assert.fail("Rematch was picked by an unrelated game client");
+    } catch (e) {
+      assert.include(e.toString(), "RpsBasicClientMismatch");
+    }
+
+    await rematchGame(gamePda, 0);
+    await completeHashGame(gamePda, {paper: {}}, nextSalt);
+
+    gameData = await program.account.rpsBasicGame.fetchNullable(gamePda);
+    assert(gameData === null, "Game should be closed once completed");
+
+    let statsAfter = await program.account.rpsBasicPlayer.fetch(
+      player1RpsBasicPda
+    );
+    assert(
+      statsAfter.totalWins.sub(statsBefore.totalWins).eqn(1),
+      "Player1 should win the rematch"
+    );
+    assert(
+      statsAfter.totalRounds.sub(statsBefore.totalRounds).eqn(2),
+      "Both rounds should be counted"
+    );
+  });
+
+  it("Invalid player2 choice is rejected", async () => {
+    let salt = Buffer.alloc(32, 12);
+    let nextSalt = Buffer.alloc(32, 13);
+    let gamePda = await initHashGame(0, salt, {maxRematches: 1});
+
+    try {
+      await joinGame(gamePda, 3);
+      assert.fail("Game was joined with an invalid choice");
+    } catch (e) {
+      assert.include(e.toString(), "RpsBasicInvalidChoice");
+    }
+
+    await joinGame(gamePda, 0);
+
+    let gameId = (await program.account.rpsBasicGame.fetch(gamePda)).id;
+    let roundId = gameId.or(new BN(1).shln(56));
+    await completeHashGame(
+      gamePda,
+      {rock: {}},
+      salt,
+      hashChoice(1, nextSalt, roundId, gameClientPda)
+    );
+
+    try {
+      await rematchGame(gamePda, 3);
+      assert.fail("Rematch was picked with an invalid choice");
+    } catch (e) {
+      assert.include(e.toString(), "RpsBasicInvalidChoice");
+    }
+
+    await rematchGame(gamePda, 0);
+    await completeHashGame(gamePda, {paper: {}}, nextSalt);
+  });
+
+  it("Client fee override is snapshotted on new games", async () => {
+    await setClientFee(200);
+
+    let gamePda = await initHashGame(1, Buffer.alloc(32, 14));
+    let gameData = await program.account.rpsBasicGame.fetch(gamePda);
+    assert(gameData.clientFee === 200, "Game should use the client override");
+    assert(gameData.platformFee === 50, "Game should use the platform fee");
+    await cancelGame(gamePda);
+
+    // Override no longer fits once the platform fee is raised
+    await updateFees(50, 9800);
+    try {
+      await initHashGame(1, Buffer.alloc(32, 14));
+      assert.fail("Game was created with fees above 100%");
+    } catch (e) {
+      assert.include(e.toString(), "InvalidFeeBps");
+    }
+
+    await updateFees(50, 50);
+    await setClientFee(null);
+  });
+
+  it("Disabled verifier can't be used for new games", async () => {
+    const vkeyHash =
+      "0x00c4cf1292d6730be2cfdebe7a064a26bd09db12a6f5a547a46db8e72c72acd8";
+    let [verifierPda] = PublicKey.findProgramAddressSync(
+      [Buffer.from("verifier_config"), Buffer.from([0]), Buffer.from([1, 0])],
+      program.programId
+    );
+
+    // Same Groth16 key as the first version
+    let groth16Vk = (await program.account.verifierConfig.fetch(rpsVerifierPda))
+      .groth16Vk;
+
+    try {
+      await program.methods
+        .registerVerifier({
+          circuitType: {rpsBasic: {}},
+          version: 1,
+          vkeyHash: vkeyHash.slice(2),
+          groth16Vk,
+        })
+        .accounts({
+          admin: admin.publicKey,
+          verifierConfig: verifierPda,
+        })
+        .signers([admin])
+        .rpc();
+      assert.fail("Verifier was registered with a malformed vkey hash");
+    } catch (e) {
+      assert.include(e.toString(), "InvalidVkeyHash");
+    }
+
+    try {
+      await program.methods
+        .registerVerifier({
+          circuitType: {rpsBasic: {}},
+          version: 1,
+          vkeyHash,
+          groth16Vk: Buffer.alloc(0),
+        })
+        .accounts({
+          admin: admin.publicKey,
+          verifierConfig: verifierPda,
+        })
+        .signers([admin])
+        .rpc();
+      assert.fail("Verifier was registered without a Groth16 key");
+    } catch (e) {
+      assert.include(e.toString(), "InvalidGroth16Vk");
+    }
+
+    await program.methods
+      .registerVerifier({
+        circuitType: {rpsBasic: {}},
+        version: 1,
+        vkeyHash,
+        groth16Vk,
+      })
+      .accounts({
+        admin: admin.publicKey,
+        verifierConfig: verifierPda,
+      })
+      .signers([admin])
+      .rpc();
+
+    await setVerifierEnabled(verifierPda, false);
+
+    try {
+      await program.methods
+        .initRpsBasic({
+          expectedId: await nextGameId(),
+          amount: MIN_AMOUNT,
+          choiceHash: Array(32).fill(0),
+          revealMode: {zk: {}},
+          maxRematches: 0,
+          bestOf: 1,
+          opponent: null,
+          loginProof: null,
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          player1: player1Pda,
+          gameClient: gameClientPda,
+          clientMintConfig: clientMintConfigOf(usdcMint),
+          verifierConfig: verifierPda,
+          mint: usdcMint,
+          tokenProgram: TOKEN_PROGRAM_ID,
+          manager: managerPda,
+          vault,
+        })
+        .signers([gameClient])
+        .rpc();
+      assert.fail("Game was created with a disabled verifier");
+    } catch (e) {
+      assert.include(e.toString(), "VerifierDisabled");
+    }
+  });
+
+  it("Game settles at the fees it was created with", async () => {
+    let salt = Buffer.alloc(32, 17);
+    let gamePda = await startHashGame(1, 0, salt);
+
+    let platformBefore = await tokenBalance(platformAta);
+    let clientBefore = await tokenBalance(gameClientAta);
+
+    // Fees raised while the game is running
+    await updateFees(500, 500);
+    await completeHashGame(gamePda, {paper: {}}, salt);
+    await updateFees(50, 50);
+
+    // Created at 0.5% client fee and 0.5% platform fee
+    let snapshotFee = MIN_AMOUNT.muln(2).muln(50).divn(10000);
+    let platformAfter = await tokenBalance(platformAta);
+    let clientAfter = await tokenBalance(gameClientAta);
+
+    assert(
+      platformAfter.sub(platformBefore).eq(snapshotFee),
+      "Platform fee should be the game snapshot"
+    );
+    assert(
+      clientAfter.sub(clientBefore).eq(snapshotFee),
+      "Client fee should be the game snapshot"
+    );
+  });
+
+  it("Completed game emits its result", async () => {
+    let salt = Buffer.alloc(32, 18);
+    let gamePda = await startHashGame(1, 0, salt);
+    let gameId = (await program.account.rpsBasicGame.fetch(gamePda)).id;
+
+    let signature = await completeHashGame(gamePda, {paper: {}}, salt);
+
+    let connection = anchor.getProvider().connection;
+    await connection.confirmTransaction(signature, "confirmed");
+    let tx = await connection.getTransaction(signature, {
+      commitment: "confirmed",
+      maxSupportedTransactionVersion: 0,
+    });
+
+    let eventParser = new anchor.EventParser(program.programId, program.coder);
+    let events = [...eventParser.parseLogs(tx.meta.logMessages)];
+    let completed = events.find((e) => e.name === "rpsBasicGameCompleted");
+
+    assert(completed, "Game completed event was not emitted");
+    assert(completed.data.id.eq(gameId), "Event game id is wrong");
+    assert(
+      completed.data.gameClient.equals(gameClientPda),
+      "Event game client is wrong"
+    );
+    assert(
+      completed.data.result.player1 !== undefined,
+      "Event result is wrong"
+    );
+
+    let platformFee = MIN_AMOUNT.muln(2).muln(50).divn(10000);
+    assert(
+      completed.data.platformAmount.eq(platformFee),
+      "Event platform amount is wrong"
+    );
+  });
+
+  it("Transfer fee mint game pot is what the vault received", async () => {
+    const connection = anchor.getProvider().connection;
+    const TRANSFER_FEE_BPS = 100; // 1%
+    // Transfer fee is taken on the way into the vault
+    const deposit = MIN_AMOUNT.muln(10000 - TRANSFER_FEE_BPS).divn(10000);
+
+    // Token-2022 mint with a transfer fee extension
+    let feeMint = Keypair.generate();
+    let mintLen = getMintLen([ExtensionType.TransferFeeConfig]);
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        SystemProgram.createAccount({
+          fromPubkey: admin.publicKey,
+          newAccountPubkey: feeMint.publicKey,
+          space: mintLen,
+          lamports: await connection.getMinimumBalanceForRentExemption(mintLen),
+          programId: TOKEN_2022_PROGRAM_ID,
+        }),
+        createInitializeTransferFeeConfigInstruction(
+          feeMint.publicKey,
+          admin.publicKey,
+          admin.publicKey,
+          TRANSFER_FEE_BPS,
+          BigInt(MAX_AMOUNT.toString()),
+          TOKEN_2022_PROGRAM_ID
+        ),
+        createInitializeMintInstruction(
+          feeMint.publicKey,
+          6,
+          admin.publicKey,
+          null,
+          TOKEN_2022_PROGRAM_ID
+        )
+      ),
+      [admin, feeMint]
+    );
+
+    await program.methods
+      .registerMint(MIN_AMOUNT, MAX_AMOUNT)
+      .accounts({
+        admin: admin.publicKey,
+        mint: feeMint.publicKey,
+        tokenProgram: TOKEN_2022_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+
+    const feeAta = async (owner: PublicKey) =>
+      (
+        await getOrCreateAssociatedTokenAccount(
+          connection,
+          admin,
+          feeMint.publicKey,
+          owner,
+          true,
+          undefined,
+          undefined,
+          TOKEN_2022_PROGRAM_ID
+        )
+      ).address;
+
+    let token: GameToken = {
+      mint: feeMint.publicKey,
+      tokenProgram: TOKEN_2022_PROGRAM_ID,
+      vault: await feeAta(managerPda),
+    };
+    for (let player of [player1Pda, player2Pda]) {
+      await mintTo(
+        connection,
+        admin,
+        feeMint.publicKey,
+        await feeAta(player),
+        admin,
+        BigInt(MIN_AMOUNT.toString()),
+        [],
+        undefined,
+        TOKEN_2022_PROGRAM_ID
+      );
+    }
+    await feeAta(gameClient.publicKey);
+    await feeAta(platformAcc.publicKey);
+
+    let salt = Buffer.alloc(32, 15);
+    let gamePda = await initHashGame(1, salt, {token});
+    let game = await program.account.rpsBasicGame.fetch(gamePda);
+    assert(
+      game.player1Deposit.eq(deposit),
+      "Player1 deposit should be what the vault received"
+    );
+
+    await joinGame(gamePda, 0, token);
+    game = await program.account.rpsBasicGame.fetch(gamePda);
+    assert(
+      game.player2Deposit.eq(deposit),
+      "Player2 deposit should be what the vault received"
+    );
+    assert(
+      (await tokenBalance(token.vault)).eq(deposit.muln(2)),
+      "Vault should hold both deposits"
+    );
+
+    await completeHashGame(gamePda, {paper: {}}, salt, null, token);
+
+    assert(
+      (await tokenBalance(token.vault)).isZero(),
+      "Vault should pay out the whole pot"
+    );
+  });
+
+  it("Token game is refunded in an emergency", async () => {
+    const setPaused = (paused: boolean) =>
+      program.methods
+        .setPaused(paused)
+        .accounts({
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+    let gamePda = await startHashGame(1, 0, Buffer.alloc(32, 20));
+    let gameData = await program.account.rpsBasicGame.fetch(gamePda);
+    let player1Balance = await tokenBalance(player1PdaAta);
+    let player2Balance = await tokenBalance(player2PdaAta);
+
+    const emergencyRefund = () =>
+      program.methods
+        .emergencyRefundRpsBasic()
+        .accounts({
+          admin: admin.publicKey,
+          rpsBasicGame: gamePda,
+          player1: player1Pda,
+          player1Ata: player1PdaAta,
+          player2: player2Pda,
+          player2Ata: player2PdaAta,
+          originClient: gameClientPda,
+          joinClient: gameClientPda,
+          manager: managerPda,
+          mint: usdcMint,
+          vault,
+          tokenProgram: TOKEN_PROGRAM_ID,
+        })
+        .signers([admin])
+        .rpc();
+
+    try {
+      await emergencyRefund();
+      assert.fail("Game was refunded while the platform is running");
+    } catch (e) {
+      assert.include(e.toString(), "NotPaused");
+    }
+
+    await setPaused(true);
+    await emergencyRefund();
+    await setPaused(false);
+
+    assert(
+      (await tokenBalance(player1PdaAta))
+        .sub(player1Balance)
+        .eq(gameData.player1Deposit),
+      "Player1 should get his whole deposit back"
+    );
+    assert(
+      (await tokenBalance(player2PdaAta))
+        .sub(player2Balance)
+        .eq(gameData.player2Deposit),
+      "Player2 should get his whole deposit back"
+    );
+    let refundedGame = await program.account.rpsBasicGame.fetchNullable(
+      gamePda
+    );
+    assert(refundedGame === null, "Game should be closed once refunded");
+  });
+
+  it("Suspended game client games are settled by others", async () => {
+    const setSuspended = (suspended: boolean) =>
+      program.methods
+        .setGameClientSuspended(suspended)
+        .accounts({
+          admin: admin.publicKey,
+          gameClient: gameClientPda,
+        })
+        .signers([admin])
+        .rpc();
+    const setPaused = (paused: boolean) =>
+      program.methods
+        .setPaused(paused)
+        .accounts({
+          admin: admin.publicKey,
+        })
+        .signers([admin])
+        .rpc();
+
+    let salt = Buffer.alloc(32, 16);
+    let gamePda = await startHashGame(1, 0, salt);
+    let unjoinedGamePda = await initHashGame(1, Buffer.alloc(32, 18));
+
+    await setSuspended(true);
+
+    try {
+      await initHashGame(1, salt);
+      assert.fail("Suspended game client created a game");
+    } catch (e) {
+      assert.include(e.toString(), "GameClientSuspended");
+    }
+
+    try {
+      await completeHashGame(gamePda, {paper: {}}, salt);
+      assert.fail("Suspended game client completed a game");
+    } catch (e) {
+      assert.include(e.toString(), "GameClientSuspended");
+    }
+
+    try {
+      await cancelGame(unjoinedGamePda);
+      assert.fail("Suspended game client cancelled a game");
+    } catch (e) {
+      assert.include(e.toString(), "GameClientSuspended");
+    }
+
+    // Any other game client can complete the running game
+    await program.methods
+      .completeRpsBasic({
+        proof: Buffer.alloc(0),
+        salt: Array.from(salt),
+        player1Choice: {paper: {}},
+        nextChoiceHash: null,
+      })
+      .accounts({
+        signer: otherClient.publicKey,
+        rpsBasicGame: gamePda,
+        verifierConfig: null,
+        player1: player1Pda,
+        player1RpsBasicPda,
+        player2: player2Pda,
+        player2RpsBasicPda,
+        rpsBasicResult: null,
+        gameClient: otherClientPda,
+        originClient: gameClientPda,
+        joinClient: gameClientPda,
+        manager: managerPda,
+        ...usdc(),
+      })
+      .signers([otherClient])
+      .rpc();
+
+    let gameData = await program.account.rpsBasicGame.fetchNullable(gamePda);
+    assert(gameData === null, "Game should be settled");
+
+    // Admin refunds the game nobody joined
+    let player1Balance = await tokenBalance(player1PdaAta);
+    await setPaused(true);
+    await program.methods
+      .emergencyRefundRpsBasic()
+      .accounts({
+        admin: admin.publicKey,
+        rpsBasicGame: unjoinedGamePda,
+        player1: player1Pda,
+        player1Ata: player1PdaAta,
+        player2: null,
+        player2Ata: null,
+        originClient: gameClientPda,
+        joinClient: null,
+        manager: managerPda,
+        mint: usdcMint,
+        vault,
+        tokenProgram: TOKEN_PROGRAM_ID,
+      })
+      .signers([admin])
+      .rpc();
+    await setPaused(false);
+
+    assert(
+      (await tokenBalance(player1PdaAta)).sub(player1Balance).eq(MIN_AMOUNT),
+      "Player1 should get his whole stake back"
+    );
+    gameData = await program.account.rpsBasicGame.fetchNullable(
+      unjoinedGamePda
+    );
+    assert(gameData === null, "Unjoined game should be refunded");
+
+    await setSuspended(false);
+  });
+
+  it("Migrated game client signer settles running games", async () => {
+    const connection = anchor.getProvider().connection;
+    const newSigner = Keypair.generate();
+    const migrateSigner = (signer: PublicKey) =>
+      program.methods
+        .migrateGameClientSigner(signer)
+        .accounts({
+          admin: admin.publicKey,
+          gameClient: gameClientPda,
+        })
+        .signers([admin])
+        .rpc();
+
+    await sendAndConfirmTransaction(
+      connection,
+      new Transaction().add(
+        SystemProgram.transfer({
+          fromPubkey: admin.publicKey,
+          toPubkey: newSigner.publicKey,
+          lamports: LAMPORTS_PER_SOL / 10,
+        })
+      ),
+      [admin]
+    );
+    let newSignerAta = (
+      await getOrCreateAssociatedTokenAccount(
+        connection,
+        admin,
+        usdcMint,
+        newSigner.publicKey
+      )
+    ).address;
+
+    let salt = Buffer.alloc(32, 19);
+    let gamePda = await startHashGame(1, 0, salt);
+    let gameData = await program.account.rpsBasicGame.fetch(gamePda);
+
+    await migrateSigner(newSigner.publicKey);
+
+    try {
+      await completeHashGame(gamePda, {paper: {}}, salt);
+      assert.fail("Old game client signer completed a game");
+    } catch (e) {
+      assert.include(e.toString(), "ConstraintHasOne");
+    }
+
+    await program.methods
+      .completeRpsBasic({
+        proof: Buffer.alloc(0),
+        salt: Array.from(salt),
+        player1Choice: {paper: {}},
+        nextChoiceHash: null,
+      })
+      .accounts({
+        signer: newSigner.publicKey,
+        rpsBasicGame: gamePda,
+        verifierConfig: null,
+        player1: player1Pda,
+        player1RpsBasicPda,
+        player2: player2Pda,
+        player2RpsBasicPda,
+        rpsBasicResult: null,
+        gameClient: gameClientPda,
+        originClient: gameClientPda,
+        originClientAta: newSignerAta,
+        joinClient: gameClientPda,
+        joinClientAta: newSignerAta,
+        manager: managerPda,
+        platformAta,
+        ...usdc(),
+      })
+      .signers([newSigner])
+      .rpc();
+
+    // Both client fee shares go to the new signer
+    let clientFee = gameData.player1Deposit
+      .add(gameData.player2Deposit)
+      .muln(gameData.clientFee)
+      .divn(10000);
+    assert(
+      (await tokenBalance(newSignerAta)).eq(clientFee),
+      "Client fee should be paid to the new signer"
+    );
+
+    await migrateSigner(gameClient.publicKey);
+  });
+
+  it("Game client can only be closed without open games", async () => {
+    const closeGameClient = (client: PublicKey) =>
+      program.methods
+        .closeGameClient()
+        .accounts({
+          admin: admin.publicKey,
+          gameClient: client,
+        })
+        .signers([admin])
+        .rpc();
+
+    let openGames = (await program.account.gameClient.fetch(gameClientPda))
+      .openGames;
+    let gamePda = await initHashGame(1, Buffer.alloc(32, 15));
+
+    let clientData = await program.account.gameClient.fetch(gameClientPda);
+    assert(clientData.openGames === openGames + 1, "Game should be open");
+
+    try {
+      await closeGameClient(gameClientPda);
+      assert.fail("Game client was closed with an open game");
+    } catch (e) {
+      assert.include(e.toString(), "GameClientHasOpenGames");
+    }
+
+    await cancelGame(gamePda);
+
+    clientData = await program.account.gameClient.fetch(gameClientPda);
+    assert(clientData.openGames === openGames, "Game should be closed");
+
+    // Other client only took part in settled games
+    await closeGameClient(otherClientPda);
+  });
+
+  it("Player limits and self-exclusion need a login proof", async () => {
+    // Game client can't change them without a valid player login proof
+    try {
+      await program.methods
+        .setPlayerLimits({
+          mint: usdcMint,
+          maxStake: null,
+          maxDailyWager: null,
+          loginProof: Buffer.alloc(260),
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          player: player1Pda,
+          gameClient: gameClientPda,
+          loginVerifier: loginVerifierPda,
+        })
+        .signers([gameClient])
+        .rpc();
+      assert.fail("Game client changed the player limits without a proof");
+    } catch (e) {
+      assert.include(e.toString(), "PlayerProofVerify");
+    }
+
+    try {
+      await program.methods
+        .setPlayerExclusion({
+          excludedUntil: new anchor.BN(0),
+          loginProof: Buffer.alloc(260),
+        })
+        .accounts({
+          signer: gameClient.publicKey,
+          player: player1Pda,
+          gameClient: gameClientPda,
+          loginVerifier: loginVerifierPda,
+        })
+        .signers([gameClient])
+        .rpc();
+      assert.fail("Game client changed the player exclusion without a proof");
+    } catch (e) {
+      assert.include(e.toString(), "PlayerProofVerify");
+    }
+
+    // Limits account is created with the player first game in the mint
+    let [playerLimitsPda, playerLimitsBump] = PublicKey.findProgramAddressSync(
+      [
+        Buffer.from("player_limits"),
+        player1Pda.toBuffer(),
+        usdcMint.toBuffer(),
+      ],
+      program.programId
+    );
+    let playerLimits = await program.account.playerLimits.fetch(
+      playerLimitsPda
+    );
+    assert(playerLimits.maxStake === null, "Player limits should not change");
+    assert(
+      playerLimits.bump === playerLimitsBump,
+      "Player limits should store their bump"
+    );
+  });
 });